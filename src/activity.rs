@@ -0,0 +1,88 @@
+use chrono::{Datelike, Duration, NaiveDate};
+use std::collections::BTreeMap;
+
+use crate::data::JobApplication;
+
+// The heatmap covers this many whole weeks, ending with the current one
+pub const HEATMAP_WEEKS: i64 = 53;
+
+/// Applications submitted on each day, by index into the job list
+pub fn applications_by_day(jobs: &[JobApplication]) -> BTreeMap<NaiveDate, Vec<usize>> {
+    let mut days: BTreeMap<NaiveDate, Vec<usize>> = BTreeMap::new();
+    for (index, job) in jobs.iter().enumerate() {
+        if let Some(date) = job.applied_on() {
+            days.entry(date).or_default().push(index);
+        }
+    }
+    days
+}
+
+/// Runs of consecutive days with at least one application
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Streaks {
+    pub current: u32,
+    pub longest: u32,
+}
+
+pub fn streaks(days: &BTreeMap<NaiveDate, Vec<usize>>, today: NaiveDate) -> Streaks {
+    let mut longest = 0;
+    let mut run = 0;
+    let mut previous: Option<NaiveDate> = None;
+
+    // Days after today can only come from typos, so they do not count
+    for &day in days.keys().filter(|day| **day <= today) {
+        run = match previous {
+            Some(prev) if day - prev == Duration::days(1) => run + 1,
+            _ => 1,
+        };
+        longest = longest.max(run);
+        previous = Some(day);
+    }
+
+    // A streak is still alive until a whole day passes without an application
+    let current = match previous {
+        Some(last) if today - last <= Duration::days(1) => run,
+        _ => 0,
+    };
+
+    Streaks { current, longest }
+}
+
+/// First day shown on the heatmap: the Monday starting its earliest week
+pub fn heatmap_start(today: NaiveDate) -> NaiveDate {
+    let this_monday = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+    this_monday - Duration::weeks(HEATMAP_WEEKS - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn applied(dates: &[&str]) -> Vec<JobApplication> {
+        dates
+            .iter()
+            .map(|date| JobApplication { date_applied: date.to_string(), ..Default::default() })
+            .collect()
+    }
+
+    fn date(value: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn streak_continues_across_a_month_boundary() {
+        let jobs = applied(&["2026-01-30", "2026-01-31", "2026-01-31", "2026-02-01", "2026-02-02"]);
+        let days = applications_by_day(&jobs);
+        assert_eq!(streaks(&days, date("2026-02-02")), Streaks { current: 4, longest: 4 });
+        // Still alive the day after, broken the day after that
+        assert_eq!(streaks(&days, date("2026-02-03")).current, 4);
+        assert_eq!(streaks(&days, date("2026-02-04")), Streaks { current: 0, longest: 4 });
+    }
+
+    #[test]
+    fn gap_resets_the_current_streak() {
+        let jobs = applied(&["2026-02-26", "2026-02-27", "2026-02-28", "2026-03-02"]);
+        let days = applications_by_day(&jobs);
+        assert_eq!(streaks(&days, date("2026-03-02")), Streaks { current: 1, longest: 3 });
+    }
+}
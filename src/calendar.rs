@@ -0,0 +1,95 @@
+use chrono::{Datelike, Months, NaiveDate};
+use std::collections::BTreeMap;
+
+use crate::data::{DeadlineKind, EventKind, JobApplication};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ViewMode {
+    #[default]
+    List,
+    Calendar,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntryKind {
+    Applied,
+    Event(EventKind),
+    Deadline(DeadlineKind),
+}
+
+/// Something that happens to an application on a given day
+#[derive(Debug, Clone)]
+pub struct Entry {
+    pub job: usize,
+    pub kind: EntryKind,
+    pub label: String,
+}
+
+// Every dated item of one application
+pub fn job_dates(job: &JobApplication) -> Vec<(NaiveDate, EntryKind, String)> {
+    let mut dates: Vec<_> = job
+        .applied_on()
+        .map(|date| (date, EntryKind::Applied, job.company.clone()))
+        .into_iter()
+        .collect();
+    dates.extend(job.events.iter().filter_map(|event| {
+        let label = match event.at() {
+            Some(time) => format!("{} {}", time.format("%H:%M"), job.company),
+            None => job.company.clone(),
+        };
+        event.on().map(|date| (date, EntryKind::Event(event.kind), label))
+    }));
+    dates.extend(job.deadlines.iter().filter_map(|deadline| {
+        let label = format!("{}: {}", deadline.kind, job.company);
+        deadline.on().map(|date| (date, EntryKind::Deadline(deadline.kind), label))
+    }));
+    dates
+}
+
+pub fn has_date(job: &JobApplication, day: NaiveDate) -> bool {
+    job_dates(job).iter().any(|(date, _, _)| *date == day)
+}
+
+pub fn first_of_month(date: NaiveDate) -> NaiveDate {
+    date.with_day(1).unwrap_or(date)
+}
+
+// The first of the month `offset` months away
+pub fn shift_month(month: NaiveDate, offset: i32) -> NaiveDate {
+    let shifted = if offset >= 0 {
+        month.checked_add_months(Months::new(offset as u32))
+    } else {
+        month.checked_sub_months(Months::new(offset.unsigned_abs()))
+    };
+    first_of_month(shifted.unwrap_or(month))
+}
+
+/// Days of the month's grid, Monday first, padded with the neighbouring months' days to whole weeks
+pub fn month_grid(month: NaiveDate) -> Vec<NaiveDate> {
+    let first = first_of_month(month);
+    let start = first - chrono::Duration::days(first.weekday().num_days_from_monday() as i64);
+    let last = shift_month(first, 1).pred_opt().unwrap_or(first);
+    let weeks = (last - start).num_days() / 7 + 1;
+
+    start.iter_days().take(weeks as usize * 7).collect()
+}
+
+/// Entries for the given applications that fall inside the grid's days
+pub fn entries_by_day<'a>(
+    jobs: impl Iterator<Item = (usize, &'a JobApplication)>,
+    grid: &[NaiveDate],
+) -> BTreeMap<NaiveDate, Vec<Entry>> {
+    let (Some(&from), Some(&to)) = (grid.first(), grid.last()) else {
+        return BTreeMap::new();
+    };
+
+    let mut days: BTreeMap<NaiveDate, Vec<Entry>> = BTreeMap::new();
+    for (index, job) in jobs {
+        for (date, kind, label) in job_dates(job) {
+            if date >= from && date <= to {
+                days.entry(date).or_default().push(Entry { job: index, kind, label });
+            }
+        }
+    }
+    days
+}
@@ -0,0 +1,137 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::data::JobApplication;
+use crate::duplicates::normalize_company;
+
+/// Rough headcount bracket
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CompanySize {
+    Startup,
+    Small,
+    Medium,
+    Large,
+    Enterprise,
+}
+
+impl CompanySize {
+    pub const ALL: [CompanySize; 5] = [
+        CompanySize::Startup,
+        CompanySize::Small,
+        CompanySize::Medium,
+        CompanySize::Large,
+        CompanySize::Enterprise,
+    ];
+}
+
+impl fmt::Display for CompanySize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                CompanySize::Startup => "Startup (1-50)",
+                CompanySize::Small => "Small (51-200)",
+                CompanySize::Medium => "Medium (201-1000)",
+                CompanySize::Large => "Large (1001-10000)",
+                CompanySize::Enterprise => "Enterprise (10000+)",
+            }
+        )
+    }
+}
+
+/// Someone to talk to at a company, e.g. a recruiter or hiring manager
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Contact {
+    pub name: String,
+    #[serde(default)]
+    pub role: String,
+    #[serde(default)]
+    pub email: String,
+}
+
+impl fmt::Display for Contact {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.role.is_empty() {
+            write!(f, "{}", self.name)
+        } else {
+            write!(f, "{} ({})", self.name, self.role)
+        }
+    }
+}
+
+/// A company in the registry, with the other spellings that refer to it
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Company {
+    pub name: String,  // Canonical name applications are filed under
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub website: Option<String>,
+    #[serde(default)]
+    pub industry: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<CompanySize>,
+    #[serde(default)]
+    pub notes: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub contacts: Vec<Contact>,
+}
+
+impl Company {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.trim().to_string(),
+            ..Default::default()
+        }
+    }
+
+    // Case, punctuation and legal suffixes are ignored, so "Google LLC" matches "google"
+    pub fn matches(&self, name: &str) -> bool {
+        let wanted = normalize_company(name);
+        !wanted.is_empty()
+            && std::iter::once(&self.name)
+                .chain(&self.aliases)
+                .any(|known| normalize_company(known) == wanted)
+    }
+}
+
+/// Index of the registered company a name refers to
+pub fn find_company(companies: &[Company], name: &str) -> Option<usize> {
+    companies.iter().position(|company| company.matches(name))
+}
+
+/// Canonical spelling of a company name, or the name itself if it is not registered
+pub fn canonical_name(companies: &[Company], name: &str) -> String {
+    find_company(companies, name)
+        .map(|index| companies[index].name.clone())
+        .unwrap_or_else(|| name.to_string())
+}
+
+/// File every application under its company's canonical name
+pub fn normalize_job_companies(companies: &[Company], jobs: &mut [JobApplication]) {
+    for job in jobs.iter_mut() {
+        job.company = canonical_name(companies, &job.company);
+    }
+}
+
+/// Registered companies whose name or alias starts with what has been typed so far
+pub fn suggestions<'a>(companies: &'a [Company], typed: &str) -> Vec<&'a str> {
+    let typed = typed.trim();
+    let lowered = typed.to_lowercase();
+    if typed.is_empty() {
+        return Vec::new();
+    }
+
+    companies
+        .iter()
+        .filter(|company| {
+            std::iter::once(&company.name)
+                .chain(&company.aliases)
+                .any(|known| known.to_lowercase().starts_with(&lowered))
+        })
+        .filter(|company| company.name != typed)
+        .map(|company| company.name.as_str())
+        .take(5)
+        .collect()
+}
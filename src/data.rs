@@ -1,45 +1,798 @@
+use chrono::{Local, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+use crate::company::Company;
+use crate::documents::Document;
+use crate::goals::{Goal, NetworkingEntry};
+use crate::pipeline::{Pipeline, StageOutcome};
+use crate::state::{SortColumn, SortKey, SortOrder};
+use crate::templates::Template;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct JobApplication {
+    // Stable identifier, e.g. for calendar event UIDs; assigned on load when missing
+    #[serde(default)]
+    pub id: String,
+    pub company: String,
+    pub position: String,
+    pub date_applied: String,
+    pub status: String,  // Name of a stage in the pipeline
+    pub notes: String,
+    pub url: Option<String>,
+    // Alternate postings for the same role, e.g. kept when merging duplicates
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub other_urls: Vec<String>,
+    #[serde(default)]
+    pub last_updated: Option<String>,  // Add a field to track updates
+    #[serde(default)]
+    pub history: Vec<StatusChange>,
+    // Why a rejected or withdrawn application ended
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub closure_reason: Option<ClosureReason>,
+    // Snapshot of the posting, kept compressed since postings are long
+    #[serde(default, skip_serializing_if = "String::is_empty", with = "crate::description::compressed")]
+    pub description: String,
+    // Library documents (by content hash) sent with this application
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resume: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cover_letter: Option<String>,
+    // Channel the application went through, e.g. "Referral"
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub source: String,
+    // Interviews and follow-ups scheduled for this application
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub events: Vec<ScheduledEvent>,
+    #[serde(default, skip_serializing_if = "Location::is_empty")]
+    pub location: Location,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub work_mode: Option<WorkMode>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visa_sponsorship: Option<Sponsorship>,
+    // Excitement from 1 to MAX_PRIORITY stars; unrated when None
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<u8>,
+    // Pinned applications stay at the top of the list whatever the sort
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub pinned: bool,
+    // Posting close dates, offer expirations and the like
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub deadlines: Vec<Deadline>,
+    // Archived applications are hidden from the default list but still count in statistics
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub archived: bool,
+}
+
+/// A deleted application, kept in the trash until restored or purged
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrashedJob {
+    pub job: JobApplication,
+    pub deleted_at: String,  // YYYY-MM-DD HH:MM:SS
+}
+
+impl TrashedJob {
+    pub fn deleted_on(&self) -> Option<NaiveDate> {
+        NaiveDateTime::parse_from_str(&self.deleted_at, "%Y-%m-%d %H:%M:%S")
+            .ok()
+            .map(|at| at.date())
+    }
+
+    // Day the trash lets go of it; None when the date is unknown or out of range
+    pub fn purge_on(&self, keep_days: u32) -> Option<NaiveDate> {
+        self.deleted_on()?.checked_add_signed(TimeDelta::try_days(keep_days.into())?)
+    }
+}
+
+pub const MAX_PRIORITY: u8 = 5;
+
+/// Where the role is based; any part may be left empty
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Location {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub city: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub region: String,  // State, province or county
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub country: String,
+}
+
+impl Location {
+    pub fn is_empty(&self) -> bool {
+        self.parts().next().is_none()
+    }
+
+    // Filled-in parts from most to least specific
+    pub fn parts(&self) -> impl Iterator<Item = &str> {
+        [&self.city, &self.region, &self.country]
+            .into_iter()
+            .map(|part| part.trim())
+            .filter(|part| !part.is_empty())
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.parts().collect::<Vec<_>>().join(", "))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum WorkMode {
+    OnSite,
+    Hybrid,
+    Remote,
+}
+
+impl WorkMode {
+    pub const ALL: [WorkMode; 3] = [WorkMode::OnSite, WorkMode::Hybrid, WorkMode::Remote];
+}
+
+impl fmt::Display for WorkMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                WorkMode::OnSite => "On-site",
+                WorkMode::Hybrid => "Hybrid",
+                WorkMode::Remote => "Remote",
+            }
+        )
+    }
+}
+
+// Whether the employer sponsors work visas for the role; unknown when not recorded
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Sponsorship {
+    Offered,
+    NotOffered,
+}
+
+impl Sponsorship {
+    pub const ALL: [Sponsorship; 2] = [Sponsorship::Offered, Sponsorship::NotOffered];
+}
+
+impl fmt::Display for Sponsorship {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Sponsorship::Offered => "Visa sponsorship",
+                Sponsorship::NotOffered => "No sponsorship",
+            }
+        )
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-pub enum JobStatus {
-    Applied,
-    OA,
+pub enum EventKind {
     Interview,
-    Rejected,
-    Offer,
-    Accepted,
-    Withdrawn,
-    All, // New variant for filtering all statuses
+    FollowUp,
+}
+
+impl EventKind {
+    pub const ALL: [EventKind; 2] = [EventKind::Interview, EventKind::FollowUp];
+}
+
+impl fmt::Display for EventKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                EventKind::Interview => "Interview",
+                EventKind::FollowUp => "Follow-up",
+            }
+        )
+    }
+}
+
+/// An interview round or follow-up reminder on a given day
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScheduledEvent {
+    pub id: String,
+    pub kind: EventKind,
+    pub date: String,  // YYYY-MM-DD
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub time: String,  // HH:MM local time; empty for all-day
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub title: String,  // e.g. "Technical round with the platform team"
+}
+
+impl ScheduledEvent {
+    pub fn on(&self) -> Option<NaiveDate> {
+        NaiveDate::parse_from_str(self.date.trim(), "%Y-%m-%d").ok()
+    }
+
+    pub fn at(&self) -> Option<NaiveTime> {
+        NaiveTime::parse_from_str(self.time.trim(), "%H:%M").ok()
+    }
+
+    // Title shown in lists and calendars, falling back to the kind
+    pub fn label(&self) -> String {
+        if self.title.trim().is_empty() {
+            self.kind.to_string()
+        } else {
+            format!("{}: {}", self.kind, self.title.trim())
+        }
+    }
+}
+
+// Deadlines are shown as due soon within a week, and warned about in the header within two days
+pub const DEADLINE_SOON_DAYS: i64 = 7;
+pub const DEADLINE_URGENT_HOURS: i64 = 48;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DeadlineKind {
+    ApplicationClose,
+    OfferExpiration,
+    Other,
+}
+
+impl DeadlineKind {
+    pub const ALL: [DeadlineKind; 3] = [
+        DeadlineKind::ApplicationClose,
+        DeadlineKind::OfferExpiration,
+        DeadlineKind::Other,
+    ];
+}
+
+impl fmt::Display for DeadlineKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                DeadlineKind::ApplicationClose => "Applications close",
+                DeadlineKind::OfferExpiration => "Offer expires",
+                DeadlineKind::Other => "Deadline",
+            }
+        )
+    }
+}
+
+/// A date something must happen by, optionally at a given time
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Deadline {
+    pub id: String,
+    pub kind: DeadlineKind,
+    pub date: String,  // YYYY-MM-DD
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub time: String,  // HH:MM local time; the end of the day when empty
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub note: String,  // e.g. "Verbal offer, written copy to follow"
+}
+
+impl Deadline {
+    pub fn on(&self) -> Option<NaiveDate> {
+        NaiveDate::parse_from_str(self.date.trim(), "%Y-%m-%d").ok()
+    }
+
+    pub fn at(&self) -> Option<NaiveTime> {
+        NaiveTime::parse_from_str(self.time.trim(), "%H:%M").ok()
+    }
+
+    // The moment the deadline passes
+    pub fn due(&self) -> Option<NaiveDateTime> {
+        let end_of_day = NaiveTime::from_hms_opt(23, 59, 59)?;
+        self.on().map(|date| date.and_time(self.at().unwrap_or(end_of_day)))
+    }
+
+    pub fn label(&self) -> String {
+        if self.note.trim().is_empty() {
+            self.kind.to_string()
+        } else {
+            format!("{}: {}", self.kind, self.note.trim())
+        }
+    }
+
+    // Short countdown such as "3d left", "5h left" or "passed"
+    pub fn countdown(&self, now: NaiveDateTime) -> Option<String> {
+        let remaining = self.due()? - now;
+        Some(if remaining.num_seconds() < 0 {
+            "passed".to_string()
+        } else if remaining.num_hours() < DEADLINE_URGENT_HOURS {
+            format!("{}h left", remaining.num_hours())
+        } else {
+            format!("{}d left", remaining.num_days())
+        })
+    }
+}
+
+pub fn new_id() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ReasonCategory {
+    NoResponse,
+    FailedScreen,
+    FailedTechnical,
+    FailedFinalRound,
+    CompMismatch,
+    PositionClosed,
+    AcceptedElsewhere,
+    LostInterest,
+    Other,
+}
+
+impl ReasonCategory {
+    pub const ALL: [ReasonCategory; 9] = [
+        ReasonCategory::NoResponse,
+        ReasonCategory::FailedScreen,
+        ReasonCategory::FailedTechnical,
+        ReasonCategory::FailedFinalRound,
+        ReasonCategory::CompMismatch,
+        ReasonCategory::PositionClosed,
+        ReasonCategory::AcceptedElsewhere,
+        ReasonCategory::LostInterest,
+        ReasonCategory::Other,
+    ];
+}
+
+impl fmt::Display for ReasonCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                ReasonCategory::NoResponse => "No response",
+                ReasonCategory::FailedScreen => "Failed resume screen",
+                ReasonCategory::FailedTechnical => "Failed technical",
+                ReasonCategory::FailedFinalRound => "Failed final round",
+                ReasonCategory::CompMismatch => "Comp mismatch",
+                ReasonCategory::PositionClosed => "Position closed",
+                ReasonCategory::AcceptedElsewhere => "Accepted elsewhere",
+                ReasonCategory::LostInterest => "Lost interest",
+                ReasonCategory::Other => "Other",
+            }
+        )
+    }
+}
+
+/// Structured reason recorded when an application is rejected or withdrawn
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClosureReason {
+    pub stage_reached: String,  // Furthest stage before closing
+    pub category: ReasonCategory,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub details: String,
+}
+
+/// A recorded move between pipeline stages
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StatusChange {
+    pub from: Option<String>,  // None for the initial status
+    pub to: String,
+    pub at: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub note: String,
+    #[serde(default)]
+    pub forced: bool,  // Made despite the pipeline's transition rules
+}
+
+impl JobApplication {
+    // The soonest deadline that has not passed yet
+    pub fn next_deadline(&self, now: NaiveDateTime) -> Option<NaiveDateTime> {
+        self.deadlines.iter().filter_map(Deadline::due).filter(|due| *due >= now).min()
+    }
+
+    pub fn record_status_change(&mut self, from: Option<String>, note: &str, forced: bool) {
+        self.history.push(StatusChange {
+            from,
+            to: self.status.clone(),
+            at: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            note: note.trim().to_string(),
+            forced,
+        });
+    }
+
+    pub fn applied_on(&self) -> Option<NaiveDate> {
+        NaiveDate::parse_from_str(self.date_applied.trim(), "%Y-%m-%d").ok()
+    }
+
+    // Date of the most recent change, falling back to the application date
+    pub fn last_activity(&self) -> Option<NaiveDate> {
+        self.last_updated
+            .as_deref()
+            .and_then(|ts| NaiveDateTime::parse_from_str(ts, "%Y-%m-%d %H:%M:%S").ok())
+            .map(|dt| dt.date())
+            .or_else(|| self.applied_on())
+    }
+
+    // Whether reaching this stage means the employer engaged: anything past the first stage
+    // other than withdrawing or being closed out without a reply
+    pub fn counts_as_response(&self, stage: &str, pipeline: &Pipeline) -> bool {
+        let first_stage = pipeline.stages.first().map(|stage| stage.name.as_str());
+        let ghosted = self
+            .closure_reason
+            .as_ref()
+            .is_some_and(|reason| reason.category == ReasonCategory::NoResponse);
+
+        Some(stage) != first_stage
+            && match pipeline.outcome(stage) {
+                StageOutcome::Withdrawn => false,
+                StageOutcome::Rejected => !ghosted,
+                _ => true,
+            }
+    }
+
+    // Whether the employer ever engaged with the application
+    pub fn got_response(&self, pipeline: &Pipeline) -> bool {
+        std::iter::once(&self.status)
+            .chain(self.history.iter().map(|change| &change.to))
+            .any(|stage| self.counts_as_response(stage, pipeline))
+    }
+}
+
+// Status filter options shown in the header pick list
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum StatusFilter {
+    #[default]
+    All,
+    Active,
+    Closed,
+    Only(String),
+}
+
+impl StatusFilter {
+    pub fn options(pipeline: &Pipeline) -> Vec<StatusFilter> {
+        let mut options = vec![StatusFilter::All, StatusFilter::Active, StatusFilter::Closed];
+        options.extend(pipeline.stages.iter().map(|stage| StatusFilter::Only(stage.name.clone())));
+        options
+    }
+
+    pub fn matches(&self, status: &str, pipeline: &Pipeline) -> bool {
+        match self {
+            StatusFilter::All => true,
+            StatusFilter::Active => pipeline.is_active(status),
+            StatusFilter::Closed => !pipeline.is_active(status),
+            StatusFilter::Only(only) => only == status,
+        }
+    }
+}
+
+impl fmt::Display for StatusFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StatusFilter::All => write!(f, "All"),
+            StatusFilter::Active => write!(f, "Active"),
+            StatusFilter::Closed => write!(f, "Closed"),
+            StatusFilter::Only(status) => write!(f, "{}", status),
+        }
+    }
+}
+
+// Source filter options shown in the header pick list
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum SourceFilter {
+    #[default]
+    All,
+    Unspecified,
+    Only(String),
+}
+
+impl SourceFilter {
+    // Configured sources plus any others recorded on applications
+    pub fn options(configured: &[String], jobs: &[JobApplication]) -> Vec<SourceFilter> {
+        let mut sources: Vec<&str> = configured.iter().map(String::as_str).collect();
+        for job in jobs {
+            if !job.source.is_empty() && !sources.iter().any(|s| s.eq_ignore_ascii_case(&job.source)) {
+                sources.push(&job.source);
+            }
+        }
+
+        let mut options = vec![SourceFilter::All, SourceFilter::Unspecified];
+        options.extend(sources.into_iter().map(|source| SourceFilter::Only(source.to_string())));
+        options
+    }
+
+    pub fn matches(&self, source: &str) -> bool {
+        match self {
+            SourceFilter::All => true,
+            SourceFilter::Unspecified => source.trim().is_empty(),
+            SourceFilter::Only(only) => only.eq_ignore_ascii_case(source.trim()),
+        }
+    }
+}
+
+impl fmt::Display for SourceFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SourceFilter::All => write!(f, "All sources"),
+            SourceFilter::Unspecified => write!(f, "No source"),
+            SourceFilter::Only(source) => write!(f, "{}", source),
+        }
+    }
+}
+
+// Location filter options: every country, region and city recorded on an application
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum LocationFilter {
+    #[default]
+    All,
+    Unspecified,
+    Only(String),
+}
+
+impl LocationFilter {
+    pub fn options(jobs: &[JobApplication]) -> Vec<LocationFilter> {
+        let mut places: Vec<&str> = Vec::new();
+        for job in jobs {
+            for part in job.location.parts() {
+                if !places.iter().any(|p| p.eq_ignore_ascii_case(part)) {
+                    places.push(part);
+                }
+            }
+        }
+        places.sort_by_key(|place| place.to_lowercase());
+
+        let mut options = vec![LocationFilter::All, LocationFilter::Unspecified];
+        options.extend(places.into_iter().map(|place| LocationFilter::Only(place.to_string())));
+        options
+    }
+
+    // A place matches the city, region or country, so picking a country covers its cities
+    pub fn matches(&self, location: &Location) -> bool {
+        match self {
+            LocationFilter::All => true,
+            LocationFilter::Unspecified => location.is_empty(),
+            LocationFilter::Only(place) => location.parts().any(|part| part.eq_ignore_ascii_case(place)),
+        }
+    }
+}
+
+impl fmt::Display for LocationFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LocationFilter::All => write!(f, "All locations"),
+            LocationFilter::Unspecified => write!(f, "No location"),
+            LocationFilter::Only(place) => write!(f, "{}", place),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum WorkModeFilter {
+    #[default]
+    All,
+    Unspecified,
+    Only(WorkMode),
+}
+
+impl WorkModeFilter {
+    pub const ALL: [WorkModeFilter; 5] = [
+        WorkModeFilter::All,
+        WorkModeFilter::Only(WorkMode::OnSite),
+        WorkModeFilter::Only(WorkMode::Hybrid),
+        WorkModeFilter::Only(WorkMode::Remote),
+        WorkModeFilter::Unspecified,
+    ];
+
+    pub fn matches(&self, work_mode: Option<WorkMode>) -> bool {
+        match self {
+            WorkModeFilter::All => true,
+            WorkModeFilter::Unspecified => work_mode.is_none(),
+            WorkModeFilter::Only(only) => work_mode == Some(*only),
+        }
+    }
 }
 
-impl fmt::Display for JobStatus {
+impl fmt::Display for WorkModeFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WorkModeFilter::All => write!(f, "Any work mode"),
+            WorkModeFilter::Unspecified => write!(f, "No work mode"),
+            WorkModeFilter::Only(mode) => write!(f, "{}", mode),
+        }
+    }
+}
+
+// How the application list is split into sections
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum GroupBy {
+    #[default]
+    None,
+    Country,
+    City,
+    WorkMode,
+    Sponsorship,
+}
+
+impl GroupBy {
+    pub const ALL: [GroupBy; 5] = [
+        GroupBy::None,
+        GroupBy::Country,
+        GroupBy::City,
+        GroupBy::WorkMode,
+        GroupBy::Sponsorship,
+    ];
+
+    // Section heading for the job, or None when the field is not recorded
+    pub fn key(&self, job: &JobApplication) -> Option<String> {
+        let non_empty = |value: &str| Some(value.trim().to_string()).filter(|v| !v.is_empty());
+        match self {
+            GroupBy::None => None,
+            GroupBy::Country => non_empty(&job.location.country),
+            GroupBy::City => non_empty(&job.location.city),
+            GroupBy::WorkMode => job.work_mode.map(|mode| mode.to_string()),
+            GroupBy::Sponsorship => job.visa_sponsorship.map(|sponsorship| sponsorship.to_string()),
+        }
+    }
+
+    // Heading for jobs without the field
+    pub fn unspecified_label(&self) -> &'static str {
+        match self {
+            GroupBy::None => "",
+            GroupBy::Country => "No country",
+            GroupBy::City => "No city",
+            GroupBy::WorkMode => "No work mode",
+            GroupBy::Sponsorship => "Sponsorship unknown",
+        }
+    }
+}
+
+impl fmt::Display for GroupBy {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
             "{}",
             match self {
-                JobStatus::Applied => "Applied",
-                JobStatus::OA => "OA",
-                JobStatus::Interview => "Interview",
-                JobStatus::Rejected => "Rejected",
-                JobStatus::Offer => "Offer",
-                JobStatus::Accepted => "Accepted",
-                JobStatus::Withdrawn => "Withdrawn",
-                JobStatus::All => "All", // Add display for "All" status
+                GroupBy::None => "No grouping",
+                GroupBy::Country => "Group by country",
+                GroupBy::City => "Group by city",
+                GroupBy::WorkMode => "Group by work mode",
+                GroupBy::Sponsorship => "Group by sponsorship",
             }
         )
     }
 }
 
+/// A named combination of filter and sort settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedView {
+    pub name: String,
+    #[serde(default)]
+    pub filter_status: StatusFilter,
+    #[serde(default)]
+    pub filter_source: SourceFilter,
+    #[serde(default)]
+    pub filter_location: LocationFilter,
+    #[serde(default)]
+    pub filter_work_mode: WorkModeFilter,
+    #[serde(default)]
+    pub sponsorship_only: bool,
+    #[serde(default)]
+    pub group_by: GroupBy,
+    #[serde(default)]
+    pub search_query: String,
+    #[serde(default)]
+    pub sort_by_relevance: bool,
+    #[serde(default)]
+    pub sort_keys: Vec<SortKey>,
+    #[serde(default)]
+    pub date_from: String,
+    #[serde(default)]
+    pub date_to: String,
+    #[serde(default)]
+    pub stale_days: String,
+}
+
+impl SavedView {
+    /// Views that ship with the app and cannot be deleted
+    pub fn builtins() -> Vec<SavedView> {
+        vec![
+            SavedView {
+                name: "Needs follow-up".to_string(),
+                filter_status: StatusFilter::Active,
+                filter_source: SourceFilter::All,
+                filter_location: LocationFilter::All,
+                filter_work_mode: WorkModeFilter::All,
+                sponsorship_only: false,
+                group_by: GroupBy::None,
+                search_query: String::new(),
+                sort_by_relevance: false,
+                sort_keys: vec![SortKey::new(SortColumn::DateApplied, SortOrder::Ascending)],
+                date_from: String::new(),
+                date_to: String::new(),
+                stale_days: "14".to_string(),
+            },
+            SavedView {
+                name: "Active".to_string(),
+                filter_status: StatusFilter::Active,
+                filter_source: SourceFilter::All,
+                filter_location: LocationFilter::All,
+                filter_work_mode: WorkModeFilter::All,
+                sponsorship_only: false,
+                group_by: GroupBy::None,
+                search_query: String::new(),
+                sort_by_relevance: false,
+                sort_keys: vec![
+                    SortKey::new(SortColumn::Status, SortOrder::Descending),
+                    SortKey::new(SortColumn::DateApplied, SortOrder::Descending),
+                ],
+                date_from: String::new(),
+                date_to: String::new(),
+                stale_days: String::new(),
+            },
+            SavedView {
+                name: "Closed".to_string(),
+                filter_status: StatusFilter::Closed,
+                filter_source: SourceFilter::All,
+                filter_location: LocationFilter::All,
+                filter_work_mode: WorkModeFilter::All,
+                sponsorship_only: false,
+                group_by: GroupBy::None,
+                search_query: String::new(),
+                sort_by_relevance: false,
+                sort_keys: vec![SortKey::new(SortColumn::LastUpdated, SortOrder::Descending)],
+                date_from: String::new(),
+                date_to: String::new(),
+                stale_days: String::new(),
+            },
+        ]
+    }
+}
+
+/// Channels offered when recording where an application came from
+pub fn default_sources() -> Vec<String> {
+    ["LinkedIn", "Company website", "Referral", "Recruiter", "Job board"]
+        .into_iter()
+        .map(String::from)
+        .collect()
+}
+
+/// User preferences persisted next to the job data
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct JobApplication {
-    pub company: String,
-    pub position: String,
-    pub date_applied: String,
-    pub status: JobStatus,
-    pub notes: String,
-    pub url: Option<String>,
+pub struct Settings {
     #[serde(default)]
-    pub last_updated: Option<String>,  // Add a field to track updates
+    pub saved_views: Vec<SavedView>,
+    #[serde(default = "default_sources")]
+    pub sources: Vec<String>,
+    #[serde(default)]
+    pub templates: Vec<Template>,
+    #[serde(default)]
+    pub goals: Vec<Goal>,
+    #[serde(default)]
+    pub live_calendar: bool,  // Keep an .ics file next to the data file up to date
+    #[serde(default = "default_trash_days")]
+    pub trash_days: u32,  // Trashed applications are purged after this many days
+}
+
+// Upper bound on the retention setting, about ten years
+pub const MAX_TRASH_DAYS: u32 = 3650;
+
+fn default_trash_days() -> u32 {
+    30
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            saved_views: Vec::new(),
+            sources: default_sources(),
+            templates: Vec::new(),
+            goals: Vec::new(),
+            live_calendar: false,
+            trash_days: default_trash_days(),
+        }
+    }
+}
+
+/// Everything stored in the data file
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TrackerData {
+    #[serde(default)]
+    pub pipeline: Pipeline,
+    #[serde(default)]
+    pub companies: Vec<Company>,
+    #[serde(default)]
+    pub documents: Vec<Document>,
+    #[serde(default)]
+    pub networking: Vec<NetworkingEntry>,
+    pub jobs: Vec<JobApplication>,
+    #[serde(default)]
+    pub trash: Vec<TrashedJob>,
 }
@@ -0,0 +1,113 @@
+use std::fs;
+use std::io::{Read, Write};
+use std::path::Path;
+
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ImportError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Unsupported file type: {0} (use .html, .pdf or .txt)")]
+    Unsupported(String),
+
+    #[error("Failed to read HTML: {0}")]
+    Html(String),
+
+    #[error("Failed to read PDF: {0}")]
+    Pdf(String),
+}
+
+/// Pull the readable text out of a saved posting page, PDF or plain text file
+pub fn import_file(path: &Path) -> Result<String, ImportError> {
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(str::to_lowercase)
+        .unwrap_or_default();
+
+    let text = match extension.as_str() {
+        "html" | "htm" => {
+            let bytes = fs::read(path)?;
+            html2text::from_read(bytes.as_slice(), 100).map_err(|err| ImportError::Html(err.to_string()))?
+        }
+        "pdf" => extract_pdf(path)?,
+        "txt" | "md" => fs::read_to_string(path)?,
+        other => return Err(ImportError::Unsupported(other.to_string())),
+    };
+
+    Ok(tidy(&text))
+}
+
+// pdf-extract panics on some malformed files, so a panic is reported like any other read failure
+fn extract_pdf(path: &Path) -> Result<String, ImportError> {
+    std::panic::catch_unwind(|| pdf_extract::extract_text(path))
+        .map_err(|panic| {
+            let reason = panic
+                .downcast_ref::<&str>()
+                .map(|reason| reason.to_string())
+                .or_else(|| panic.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "the file appears to be malformed".to_string());
+            ImportError::Pdf(reason)
+        })?
+        .map_err(|err| ImportError::Pdf(err.to_string()))
+}
+
+// Trim trailing spaces and collapse runs of blank lines left over from page layout
+fn tidy(text: &str) -> String {
+    let mut lines: Vec<&str> = Vec::new();
+    for line in text.lines().map(str::trim_end) {
+        if line.is_empty() && lines.last().is_none_or(|last| last.is_empty()) {
+            continue;
+        }
+        lines.push(line);
+    }
+    lines.join("\n").trim().to_string()
+}
+
+/// Serde helpers storing long text gzip-compressed and base64-encoded in the data file
+pub mod compressed {
+    use super::*;
+    use base64::engine::general_purpose::STANDARD;
+    use base64::Engine;
+    use flate2::read::GzDecoder;
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(text: &str, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::best());
+        encoder
+            .write_all(text.as_bytes())
+            .and_then(|_| encoder.finish())
+            .map(|bytes| STANDARD.encode(bytes))
+            .map_err(serde::ser::Error::custom)
+            .and_then(|encoded| serializer.serialize_str(&encoded))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+        let encoded = String::deserialize(deserializer)?;
+        let bytes = STANDARD.decode(encoded).map_err(de::Error::custom)?;
+
+        let mut text = String::new();
+        GzDecoder::new(bytes.as_slice())
+            .read_to_string(&mut text)
+            .map_err(de::Error::custom)?;
+        Ok(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed_pdf_is_an_import_error() {
+        let path = std::env::temp_dir().join(format!("job_tracker_malformed_{}.pdf", std::process::id()));
+        fs::write(&path, b"%PDF-1.4\n1 0 obj << /Type /Catalog /Pages 2 0 R >> endobj\ntrailer << /Root 1 0 R >>\n%%EOF").unwrap();
+        let result = import_file(&path);
+        let _ = fs::remove_file(&path);
+        assert!(matches!(result, Err(ImportError::Pdf(_))));
+    }
+}
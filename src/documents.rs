@@ -0,0 +1,138 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+use chrono::Local;
+
+use crate::data::JobApplication;
+use crate::pipeline::Pipeline;
+
+// Copies of every document live next to the data file so they survive edits to the originals
+pub const DOCUMENTS_DIR: &str = "documents";
+
+#[derive(Error, Debug)]
+pub enum DocumentError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("{0} is not a file")]
+    NotAFile(String),
+
+    #[error("This file is already in the library as \"{0}\"")]
+    AlreadyStored(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DocumentKind {
+    Resume,
+    CoverLetter,
+}
+
+impl DocumentKind {
+    pub const ALL: [DocumentKind; 2] = [DocumentKind::Resume, DocumentKind::CoverLetter];
+}
+
+impl fmt::Display for DocumentKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                DocumentKind::Resume => "Resume",
+                DocumentKind::CoverLetter => "Cover letter",
+            }
+        )
+    }
+}
+
+/// One version of a resume or cover letter stored in the library
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Document {
+    pub id: String,  // SHA-256 of the contents, hex encoded
+    pub name: String,  // e.g. "Resume v3 (backend)"
+    pub kind: DocumentKind,
+    pub file_name: String,  // Name of the copy inside DOCUMENTS_DIR
+    pub added: String,
+}
+
+impl Document {
+    pub fn path(&self) -> PathBuf {
+        Path::new(DOCUMENTS_DIR).join(&self.file_name)
+    }
+
+    // Open the stored copy in the system's default application
+    pub fn open(&self) -> std::io::Result<()> {
+        open::that_detached(self.path())
+    }
+
+    // Applications this version was sent with
+    pub fn sent_with<'a>(&self, jobs: &'a [JobApplication]) -> Vec<&'a JobApplication> {
+        jobs.iter()
+            .filter(|job| match self.kind {
+                DocumentKind::Resume => job.resume.as_deref() == Some(self.id.as_str()),
+                DocumentKind::CoverLetter => job.cover_letter.as_deref() == Some(self.id.as_str()),
+            })
+            .collect()
+    }
+
+    /// Applications sent with this version and how many of them got a response
+    pub fn response_stats(&self, jobs: &[JobApplication], pipeline: &Pipeline) -> (usize, usize) {
+        let sent = self.sent_with(jobs);
+        let responded = sent.iter().filter(|job| job.got_response(pipeline)).count();
+        (sent.len(), responded)
+    }
+}
+
+impl fmt::Display for Document {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+/// Copy a file into the library under its content hash
+pub fn add_to_library(
+    source: &Path,
+    name: &str,
+    kind: DocumentKind,
+    library: &[Document],
+) -> Result<Document, DocumentError> {
+    if !source.is_file() {
+        return Err(DocumentError::NotAFile(source.display().to_string()));
+    }
+
+    let contents = fs::read(source)?;
+    let id = format!("{:x}", Sha256::digest(&contents));
+    if let Some(existing) = library.iter().find(|doc| doc.id == id) {
+        return Err(DocumentError::AlreadyStored(existing.name.clone()));
+    }
+
+    // The hash prefix keeps versions with the same original file name apart
+    let original = source
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "document".to_string());
+    let file_name = format!("{}-{}", &id[..12], original);
+
+    fs::create_dir_all(DOCUMENTS_DIR)?;
+    fs::write(Path::new(DOCUMENTS_DIR).join(&file_name), &contents)?;
+
+    let name = name.trim();
+    Ok(Document {
+        id,
+        name: if name.is_empty() { original } else { name.to_string() },
+        kind,
+        file_name,
+        added: Local::now().format("%Y-%m-%d").to_string(),
+    })
+}
+
+/// Delete a document's stored copy
+pub fn remove_from_library(document: &Document) -> Result<(), DocumentError> {
+    match fs::remove_file(document.path()) {
+        Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(err.into()),
+        _ => Ok(()),
+    }
+}
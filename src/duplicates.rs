@@ -0,0 +1,341 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use chrono::Local;
+
+use crate::data::JobApplication;
+use crate::search::edit_distance;
+
+// Legal suffixes that don't distinguish one company from another
+const COMPANY_SUFFIXES: [&str; 12] = [
+    "inc", "incorporated", "llc", "ltd", "limited", "corp", "corporation", "co", "company", "gmbh",
+    "plc", "the",
+];
+
+// Common title abbreviations expanded before comparing positions
+const TITLE_ABBREVIATIONS: [(&str, &str); 8] = [
+    ("sr", "senior"),
+    ("jr", "junior"),
+    ("swe", "software engineer"),
+    ("sde", "software engineer"),
+    ("eng", "engineer"),
+    ("dev", "developer"),
+    ("mgr", "manager"),
+    ("pm", "product manager"),
+];
+
+// Query parameters that track where a click came from rather than which posting it is; utm_* are matched by prefix
+const TRACKING_PARAMS: [&str; 3] = ["ref", "trk", "gclid"];
+
+/// Why two applications look like the same role
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DuplicateReason {
+    SameUrl,
+    SimilarPosition,
+}
+
+impl fmt::Display for DuplicateReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                DuplicateReason::SameUrl => "same posting URL",
+                DuplicateReason::SimilarPosition => "same company, similar position",
+            }
+        )
+    }
+}
+
+/// Fields that can be taken from either side when merging two applications
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeField {
+    Company,
+    Position,
+    DateApplied,
+    Status,
+    Url,
+}
+
+impl MergeField {
+    pub const ALL: [MergeField; 5] = [
+        MergeField::Company,
+        MergeField::Position,
+        MergeField::DateApplied,
+        MergeField::Status,
+        MergeField::Url,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            MergeField::Company => "Company",
+            MergeField::Position => "Position",
+            MergeField::DateApplied => "Date Applied",
+            MergeField::Status => "Status",
+            MergeField::Url => "URL",
+        }
+    }
+
+    pub fn value<'a>(&self, job: &'a JobApplication) -> &'a str {
+        match self {
+            MergeField::Company => &job.company,
+            MergeField::Position => &job.position,
+            MergeField::DateApplied => &job.date_applied,
+            MergeField::Status => &job.status,
+            MergeField::Url => job.url.as_deref().unwrap_or(""),
+        }
+    }
+}
+
+/// Lowercase company name with punctuation and legal suffixes removed
+pub fn normalize_company(name: &str) -> String {
+    words(name)
+        .into_iter()
+        .filter(|word| !COMPANY_SUFFIXES.contains(&word.as_str()))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Host and path of a URL without scheme, "www.", fragment or trailing slash, followed by its query
+/// parameters in sorted order. Job boards keep the posting id in the query (Indeed's `jk`, LinkedIn's
+/// `currentJobId`), so only tracking parameters are dropped
+pub fn normalize_url(url: &str) -> String {
+    let url = url.trim();
+    let url = url.split('#').next().unwrap_or(url);
+    let (address, query) = url.split_once('?').unwrap_or((url, ""));
+
+    let address = address.to_lowercase();
+    let address = address.split_once("://").map(|(_, rest)| rest).unwrap_or(&address);
+    let address = address.strip_prefix("www.").unwrap_or(address);
+    let address = address.trim_end_matches('/');
+
+    let mut params: Vec<&str> = query
+        .split('&')
+        .filter(|param| !param.is_empty() && !is_tracking_param(param))
+        .collect();
+    if params.is_empty() {
+        return address.to_string();
+    }
+    params.sort_unstable();
+    format!("{}?{}", address, params.join("&"))
+}
+
+fn is_tracking_param(param: &str) -> bool {
+    let name = param.split('=').next().unwrap_or(param).to_lowercase();
+    name.starts_with("utm_") || TRACKING_PARAMS.contains(&name.as_str())
+}
+
+fn normalize_position(title: &str) -> Vec<String> {
+    words(title)
+        .into_iter()
+        .flat_map(|word| {
+            let expanded = TITLE_ABBREVIATIONS
+                .iter()
+                .find(|(short, _)| *short == word)
+                .map(|(_, long)| long.to_string())
+                .unwrap_or(word);
+            expanded.split(' ').map(str::to_string).collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+fn words(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+// Token overlap, falling back to character similarity for typos
+fn positions_similar(a: &str, b: &str) -> bool {
+    let a_words = normalize_position(a);
+    let b_words = normalize_position(b);
+    if a_words.is_empty() || b_words.is_empty() {
+        return false;
+    }
+
+    let a_set: HashSet<&String> = a_words.iter().collect();
+    let b_set: HashSet<&String> = b_words.iter().collect();
+    let overlap = a_set.intersection(&b_set).count() as f32;
+    let union = a_set.union(&b_set).count() as f32;
+    if overlap / union >= 0.6 {
+        return true;
+    }
+
+    let a_chars: Vec<char> = a_words.join(" ").chars().collect();
+    let b_chars: Vec<char> = b_words.join(" ").chars().collect();
+    let longest = a_chars.len().max(b_chars.len()) as f32;
+    1.0 - edit_distance(&a_chars, &b_chars) as f32 / longest >= 0.8
+}
+
+/// Decide whether two applications are probably the same role
+pub fn duplicate_reason(a: &JobApplication, b: &JobApplication) -> Option<DuplicateReason> {
+    let same_url = match (&a.url, &b.url) {
+        (Some(a_url), Some(b_url)) => {
+            let a_url = normalize_url(a_url);
+            !a_url.is_empty() && a_url == normalize_url(b_url)
+        }
+        _ => false,
+    };
+    if same_url {
+        return Some(DuplicateReason::SameUrl);
+    }
+
+    let company = normalize_company(&a.company);
+    if !company.is_empty()
+        && company == normalize_company(&b.company)
+        && positions_similar(&a.position, &b.position)
+    {
+        return Some(DuplicateReason::SimilarPosition);
+    }
+
+    None
+}
+
+/// Existing applications that look like the same role as `candidate`
+pub fn matches_for(candidate: &JobApplication, jobs: &[JobApplication]) -> Vec<(usize, DuplicateReason)> {
+    jobs.iter()
+        .enumerate()
+        .filter_map(|(index, job)| duplicate_reason(candidate, job).map(|reason| (index, reason)))
+        .collect()
+}
+
+/// All likely duplicate pairs, lower index first
+pub fn find_duplicates(jobs: &[JobApplication]) -> Vec<(usize, usize)> {
+    let mut pairs = Vec::new();
+    for (i, a) in jobs.iter().enumerate() {
+        for (j, b) in jobs.iter().enumerate().skip(i + 1) {
+            if duplicate_reason(a, b).is_some() {
+                pairs.push((i, j));
+            }
+        }
+    }
+    pairs
+}
+
+/// Combine two applications, taking the listed fields from `right` and everything else
+/// from `left`. Notes, history and URLs from both sides are kept.
+pub fn merge_jobs(left: &JobApplication, right: &JobApplication, take_right: &[MergeField]) -> JobApplication {
+    let mut merged = left.clone();
+
+    for field in take_right {
+        match field {
+            MergeField::Company => merged.company = right.company.clone(),
+            MergeField::Position => merged.position = right.position.clone(),
+            MergeField::DateApplied => merged.date_applied = right.date_applied.clone(),
+            MergeField::Status => {
+                merged.status = right.status.clone();
+                merged.closure_reason = right.closure_reason.clone();
+            }
+            MergeField::Url => merged.url = right.url.clone(),
+        }
+    }
+
+    let mut notes: Vec<&str> = vec![left.notes.trim(), right.notes.trim()];
+    notes.retain(|n| !n.is_empty());
+    notes.dedup();
+    merged.notes = notes.join("\n\n---\n\n");
+
+    // Either snapshot may be the more complete one, so keep both when they differ
+    let mut descriptions: Vec<&str> = vec![left.description.trim(), right.description.trim()];
+    descriptions.retain(|d| !d.is_empty());
+    descriptions.dedup();
+    merged.description = descriptions.join("\n\n---\n\n");
+    merged.resume = left.resume.clone().or_else(|| right.resume.clone());
+    merged.cover_letter = left.cover_letter.clone().or_else(|| right.cover_letter.clone());
+    if merged.source.is_empty() {
+        merged.source = right.source.clone();
+    }
+    if merged.location.is_empty() {
+        merged.location = right.location.clone();
+    }
+    merged.work_mode = left.work_mode.or(right.work_mode);
+    merged.visa_sponsorship = left.visa_sponsorship.or(right.visa_sponsorship);
+    merged.priority = left.priority.max(right.priority);
+    merged.pinned = left.pinned || right.pinned;
+
+    // Scheduled events and deadlines from both, without repeating any
+    merged.events = left.events.clone();
+    for event in &right.events {
+        if !merged.events.iter().any(|e| e.id == event.id) {
+            merged.events.push(event.clone());
+        }
+    }
+
+    merged.deadlines = left.deadlines.clone();
+    for deadline in &right.deadlines {
+        if !merged.deadlines.iter().any(|d| d.id == deadline.id) {
+            merged.deadlines.push(deadline.clone());
+        }
+    }
+
+    // Interleave both histories by timestamp
+    merged.history = left.history.iter().chain(&right.history).cloned().collect();
+    merged.history.sort_by(|a, b| a.at.cmp(&b.at));
+
+    // Every URL that isn't the primary one is kept as an alternate
+    let mut seen: HashSet<String> = merged.url.iter().map(|url| normalize_url(url)).collect();
+    merged.other_urls = [&left.url, &right.url]
+        .into_iter()
+        .flatten()
+        .chain(left.other_urls.iter().chain(&right.other_urls))
+        .filter(|url| seen.insert(normalize_url(url)))
+        .cloned()
+        .collect();
+
+    merged.last_updated = Some(Local::now().format("%Y-%m-%d %H:%M:%S").to_string());
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn different_postings_on_the_same_board_differ() {
+        assert_ne!(
+            normalize_url("https://www.indeed.com/viewjob?jk=4f1a2b3c4d5e6f70"),
+            normalize_url("https://www.indeed.com/viewjob?jk=9a8b7c6d5e4f3a21"),
+        );
+    }
+
+    #[test]
+    fn tracking_params_and_fragment_are_ignored() {
+        let plain = normalize_url("https://boards.greenhouse.io/acme/jobs?token=12345");
+        assert_eq!(
+            normalize_url("http://boards.greenhouse.io/acme/jobs/?utm_source=linkedin&token=12345&utm_medium=social#apply"),
+            plain,
+        );
+        assert_eq!(
+            normalize_url("https://www.linkedin.com/jobs/view/?trk=feed&currentJobId=987&gclid=x"),
+            "linkedin.com/jobs/view?currentJobId=987",
+        );
+    }
+
+    #[test]
+    fn param_order_does_not_matter() {
+        assert_eq!(
+            normalize_url("https://jobs.example.com/apply?b=2&a=1"),
+            normalize_url("https://jobs.example.com/apply?a=1&b=2"),
+        );
+    }
+
+    #[test]
+    fn same_url_is_a_duplicate_but_another_posting_is_not() {
+        let job = |url: &str| JobApplication {
+            company: "Acme".to_string(),
+            position: "Engineer".to_string(),
+            url: Some(url.to_string()),
+            ..Default::default()
+        };
+        let first = job("https://www.indeed.com/viewjob?jk=111&utm_campaign=alert");
+        assert_eq!(
+            duplicate_reason(&first, &job("https://indeed.com/viewjob?jk=111")),
+            Some(DuplicateReason::SameUrl)
+        );
+        assert_ne!(
+            duplicate_reason(&first, &job("https://indeed.com/viewjob?jk=222")),
+            Some(DuplicateReason::SameUrl)
+        );
+    }
+}
@@ -0,0 +1,210 @@
+use chrono::{Datelike, Duration, Months, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::data::{EventKind, JobApplication};
+
+// Completed periods shown in each goal's history
+pub const HISTORY_PERIODS: usize = 12;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GoalMetric {
+    Applications,
+    Interviews,
+    NetworkingMessages,
+}
+
+impl GoalMetric {
+    pub const ALL: [GoalMetric; 3] = [
+        GoalMetric::Applications,
+        GoalMetric::Interviews,
+        GoalMetric::NetworkingMessages,
+    ];
+}
+
+impl fmt::Display for GoalMetric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                GoalMetric::Applications => "Applications",
+                GoalMetric::Interviews => "Interviews",
+                GoalMetric::NetworkingMessages => "Networking messages",
+            }
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GoalPeriod {
+    Weekly,
+    Monthly,
+}
+
+impl GoalPeriod {
+    pub const ALL: [GoalPeriod; 2] = [GoalPeriod::Weekly, GoalPeriod::Monthly];
+
+    // First day of the period containing the date; weeks start on Monday
+    pub fn start_of(&self, date: NaiveDate) -> NaiveDate {
+        match self {
+            GoalPeriod::Weekly => date - Duration::days(date.weekday().num_days_from_monday() as i64),
+            GoalPeriod::Monthly => date.with_day(1).unwrap_or(date),
+        }
+    }
+
+    // First day of the following period
+    pub fn next(&self, start: NaiveDate) -> NaiveDate {
+        match self {
+            GoalPeriod::Weekly => start + Duration::weeks(1),
+            GoalPeriod::Monthly => start.checked_add_months(Months::new(1)).unwrap_or(start),
+        }
+    }
+
+    pub fn previous(&self, start: NaiveDate) -> NaiveDate {
+        match self {
+            GoalPeriod::Weekly => start - Duration::weeks(1),
+            GoalPeriod::Monthly => start.checked_sub_months(Months::new(1)).unwrap_or(start),
+        }
+    }
+
+    pub fn current_label(&self) -> &'static str {
+        match self {
+            GoalPeriod::Weekly => "this week",
+            GoalPeriod::Monthly => "this month",
+        }
+    }
+}
+
+impl fmt::Display for GoalPeriod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                GoalPeriod::Weekly => "Per week",
+                GoalPeriod::Monthly => "Per month",
+            }
+        )
+    }
+}
+
+/// A quota such as "10 applications per week"
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Goal {
+    pub metric: GoalMetric,
+    pub period: GoalPeriod,
+    pub target: u32,
+}
+
+impl Default for Goal {
+    fn default() -> Self {
+        Self {
+            metric: GoalMetric::Applications,
+            period: GoalPeriod::Weekly,
+            target: 10,
+        }
+    }
+}
+
+/// A networking message sent, logged for goals since it belongs to no application
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NetworkingEntry {
+    pub date: String,  // YYYY-MM-DD
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub note: String,  // e.g. who it went to
+}
+
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d").ok()
+}
+
+/// How much of a metric happened in [start, end)
+pub fn count(
+    metric: GoalMetric,
+    jobs: &[JobApplication],
+    networking: &[NetworkingEntry],
+    start: NaiveDate,
+    end: NaiveDate,
+) -> u32 {
+    let within = |date: Option<NaiveDate>| date.is_some_and(|d| d >= start && d < end);
+    let total = match metric {
+        GoalMetric::Applications => jobs.iter().filter(|job| within(job.applied_on())).count(),
+        GoalMetric::Interviews => jobs
+            .iter()
+            .flat_map(|job| &job.events)
+            .filter(|event| event.kind == EventKind::Interview && within(event.on()))
+            .count(),
+        GoalMetric::NetworkingMessages => networking.iter().filter(|entry| within(parse_date(&entry.date))).count(),
+    };
+    total as u32
+}
+
+/// Progress towards the goal in the period containing today
+pub fn progress(goal: &Goal, jobs: &[JobApplication], networking: &[NetworkingEntry], today: NaiveDate) -> u32 {
+    let start = goal.period.start_of(today);
+    count(goal.metric, jobs, networking, start, goal.period.next(start))
+}
+
+/// The most recent completed periods, newest last, with their count and whether the target was hit
+pub fn history(
+    goal: &Goal,
+    jobs: &[JobApplication],
+    networking: &[NetworkingEntry],
+    today: NaiveDate,
+) -> Vec<(NaiveDate, u32, bool)> {
+    let mut periods = Vec::with_capacity(HISTORY_PERIODS);
+    let mut end = goal.period.start_of(today);
+    for _ in 0..HISTORY_PERIODS {
+        let start = goal.period.previous(end);
+        let done = count(goal.metric, jobs, networking, start, end);
+        periods.push((start, done, done >= goal.target));
+        end = start;
+    }
+    periods.reverse();
+    periods
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(value: &str) -> NaiveDate {
+        parse_date(value).unwrap()
+    }
+
+    fn applied(dates: &[&str]) -> Vec<JobApplication> {
+        dates
+            .iter()
+            .map(|date| JobApplication { date_applied: date.to_string(), ..Default::default() })
+            .collect()
+    }
+
+    #[test]
+    fn weekly_progress_counts_from_monday_to_today() {
+        // Wednesday 14 October 2026; the week started on Monday the 12th
+        let today = date("2026-10-14");
+        let jobs = applied(&["2026-10-11", "2026-10-12", "2026-10-13", "2026-10-14", "2026-10-14"]);
+        let networking = vec![
+            NetworkingEntry { date: "2026-10-09".to_string(), note: String::new() },
+            NetworkingEntry { date: "2026-10-13".to_string(), note: "recruiter".to_string() },
+        ];
+
+        let goal = Goal { metric: GoalMetric::Applications, period: GoalPeriod::Weekly, target: 10 };
+        assert_eq!(progress(&goal, &jobs, &networking, today), 4);
+
+        let goal = Goal { metric: GoalMetric::NetworkingMessages, ..goal };
+        assert_eq!(progress(&goal, &jobs, &networking, today), 1);
+    }
+
+    #[test]
+    fn history_leaves_out_the_week_in_progress() {
+        let today = date("2026-10-14");
+        let jobs = applied(&["2026-10-05", "2026-10-11", "2026-10-12"]);
+        let goal = Goal { target: 2, ..Goal::default() };
+
+        let periods = history(&goal, &jobs, &[], today);
+        assert_eq!(periods.len(), HISTORY_PERIODS);
+        assert_eq!(periods.last(), Some(&(date("2026-10-05"), 2, true)));
+    }
+}
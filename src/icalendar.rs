@@ -0,0 +1,218 @@
+use chrono::{Duration, NaiveDate, Utc};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::data::{Deadline, EventKind, JobApplication, ScheduledEvent};
+use crate::templates::EXPORTS_DIR;
+
+// Rewritten on every save while the live calendar is switched on, for clients that subscribe to a file
+pub const LIVE_CALENDAR_FILE: &str = "job_tracker.ics";
+
+// Calendar clients match events on UID, so these must never change for the same event
+const UID_DOMAIN: &str = "job-tracker.local";
+
+// Escape a TEXT value (RFC 5545 section 3.3.11)
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+// Content lines longer than 75 octets are folded onto continuation lines starting with a space
+fn push_line(output: &mut String, line: &str) {
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            output.push_str("\r\n ");
+            width = 1;
+        }
+        output.push(c);
+        width += c.len_utf8();
+    }
+    output.push_str("\r\n");
+}
+
+fn date_value(date: NaiveDate) -> String {
+    date.format("%Y%m%d").to_string()
+}
+
+// The application itself, as an all-day event on the day it was sent
+fn applied_event(output: &mut String, job: &JobApplication, stamp: &str) {
+    let Some(date) = job.applied_on() else {
+        return;
+    };
+
+    push_line(output, "BEGIN:VEVENT");
+    push_line(output, &format!("UID:applied-{}@{}", job.id, UID_DOMAIN));
+    push_line(output, &format!("DTSTAMP:{}", stamp));
+    push_line(output, &format!("DTSTART;VALUE=DATE:{}", date_value(date)));
+    push_line(output, &format!("DTEND;VALUE=DATE:{}", date_value(date + Duration::days(1))));
+    push_line(output, &format!("SUMMARY:{}", escape(&format!("Applied: {} at {}", job.position, job.company))));
+    push_line(output, &format!("DESCRIPTION:{}", escape(&format!("Status: {}", job.status))));
+    if let Some(url) = &job.url {
+        push_line(output, &format!("URL:{}", url));
+    }
+    push_line(output, "CATEGORIES:Application");
+    push_line(output, "TRANSP:TRANSPARENT");
+    push_line(output, "END:VEVENT");
+}
+
+fn scheduled_event(output: &mut String, job: &JobApplication, event: &ScheduledEvent, stamp: &str) {
+    let Some(date) = event.on() else {
+        return;
+    };
+
+    push_line(output, "BEGIN:VEVENT");
+    push_line(output, &format!("UID:{}@{}", event.id, UID_DOMAIN));
+    push_line(output, &format!("DTSTAMP:{}", stamp));
+    match event.at() {
+        // Floating local time, so the event stays at the same wall-clock time wherever it is viewed
+        Some(time) => {
+            push_line(output, &format!("DTSTART:{}", date.and_time(time).format("%Y%m%dT%H%M%S")));
+            push_line(output, match event.kind {
+                EventKind::Interview => "DURATION:PT1H",
+                EventKind::FollowUp => "DURATION:PT15M",
+            });
+        }
+        None => {
+            push_line(output, &format!("DTSTART;VALUE=DATE:{}", date_value(date)));
+            push_line(output, &format!("DTEND;VALUE=DATE:{}", date_value(date + Duration::days(1))));
+        }
+    }
+    push_line(output, &format!("SUMMARY:{}", escape(&format!("{} - {}", event.label(), job.company))));
+    push_line(output, &format!("DESCRIPTION:{}", escape(&format!("{} at {}", job.position, job.company))));
+    if let Some(url) = &job.url {
+        push_line(output, &format!("URL:{}", url));
+    }
+    push_line(output, &format!("CATEGORIES:{}", event.kind));
+
+    // Interviews remind half an hour ahead; follow-ups when they are due, or at 9:00 if all-day
+    push_line(output, "BEGIN:VALARM");
+    push_line(output, "ACTION:DISPLAY");
+    push_line(output, &format!("DESCRIPTION:{}", escape(&event.label())));
+    push_line(output, match (event.kind, event.at().is_some()) {
+        (EventKind::Interview, true) => "TRIGGER:-PT30M",
+        (_, true) => "TRIGGER:PT0M",
+        (_, false) => "TRIGGER:PT9H",
+    });
+    push_line(output, "END:VALARM");
+    push_line(output, "END:VEVENT");
+}
+
+// Deadlines are zero-length events at the moment they pass, reminding a day ahead
+fn deadline_event(output: &mut String, job: &JobApplication, deadline: &Deadline, stamp: &str) {
+    let Some(date) = deadline.on() else {
+        return;
+    };
+
+    push_line(output, "BEGIN:VEVENT");
+    push_line(output, &format!("UID:{}@{}", deadline.id, UID_DOMAIN));
+    push_line(output, &format!("DTSTAMP:{}", stamp));
+    match deadline.at() {
+        Some(time) => push_line(output, &format!("DTSTART:{}", date.and_time(time).format("%Y%m%dT%H%M%S"))),
+        None => {
+            push_line(output, &format!("DTSTART;VALUE=DATE:{}", date_value(date)));
+            push_line(output, &format!("DTEND;VALUE=DATE:{}", date_value(date + Duration::days(1))));
+        }
+    }
+    push_line(output, &format!("SUMMARY:{}", escape(&format!("{} - {}", deadline.label(), job.company))));
+    push_line(output, &format!("DESCRIPTION:{}", escape(&format!("{} at {}", job.position, job.company))));
+    if let Some(url) = &job.url {
+        push_line(output, &format!("URL:{}", url));
+    }
+    push_line(output, "CATEGORIES:Deadline");
+    push_line(output, "TRANSP:TRANSPARENT");
+
+    push_line(output, "BEGIN:VALARM");
+    push_line(output, "ACTION:DISPLAY");
+    push_line(output, &format!("DESCRIPTION:{}", escape(&deadline.label())));
+    push_line(output, "TRIGGER:-P1D");
+    push_line(output, "END:VALARM");
+    push_line(output, "END:VEVENT");
+}
+
+/// Every application date, interview, follow-up and deadline as an iCalendar document
+pub fn to_ics(jobs: &[JobApplication]) -> String {
+    let stamp = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+    let mut output = String::new();
+
+    push_line(&mut output, "BEGIN:VCALENDAR");
+    push_line(&mut output, "VERSION:2.0");
+    push_line(&mut output, "PRODID:-//Job Tracker//Job Applications//EN");
+    push_line(&mut output, "CALSCALE:GREGORIAN");
+    push_line(&mut output, "X-WR-CALNAME:Job applications");
+
+    for job in jobs {
+        applied_event(&mut output, job, &stamp);
+        for event in &job.events {
+            scheduled_event(&mut output, job, event, &stamp);
+        }
+        for deadline in &job.deadlines {
+            deadline_event(&mut output, job, deadline, &stamp);
+        }
+    }
+
+    push_line(&mut output, "END:VCALENDAR");
+    output
+}
+
+/// Write a one-off calendar export into the exports folder
+pub fn export(jobs: &[JobApplication]) -> std::io::Result<PathBuf> {
+    let path = Path::new(EXPORTS_DIR).join("job_applications.ics");
+    fs::create_dir_all(EXPORTS_DIR)?;
+    fs::write(&path, to_ics(jobs))?;
+    Ok(path)
+}
+
+/// Refresh the live calendar file next to the data file
+pub fn write_live(jobs: &[JobApplication]) -> std::io::Result<()> {
+    fs::write(LIVE_CALENDAR_FILE, to_ics(jobs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Content lines with the CRLF and folding undone
+    fn unfold(output: &str) -> String {
+        output.replace("\r\n ", "")
+    }
+
+    #[test]
+    fn short_lines_are_not_folded() {
+        let mut output = String::new();
+        push_line(&mut output, "SUMMARY:Interview");
+        assert_eq!(output, "SUMMARY:Interview\r\n");
+    }
+
+    #[test]
+    fn long_lines_fold_at_75_octets() {
+        let line = format!("DESCRIPTION:{}", "x".repeat(200));
+        let mut output = String::new();
+        push_line(&mut output, &line);
+
+        let physical: Vec<&str> = output.trim_end_matches("\r\n").split("\r\n").collect();
+        assert!(physical.len() > 1);
+        assert!(physical.iter().all(|l| l.len() <= 75));
+        assert!(physical[1..].iter().all(|l| l.starts_with(' ')));
+        assert_eq!(unfold(&output), format!("{}\r\n", line));
+    }
+
+    #[test]
+    fn folding_never_splits_a_multibyte_character() {
+        let line = format!("SUMMARY:{}", "é".repeat(100));
+        let mut output = String::new();
+        push_line(&mut output, &line);
+
+        assert!(output.split("\r\n").all(|l| l.len() <= 75));
+        assert_eq!(unfold(&output), format!("{}\r\n", line));
+    }
+
+    #[test]
+    fn text_values_are_escaped() {
+        assert_eq!(escape("a;b,c\\d\ne"), r"a\;b\,c\\d\ne");
+    }
+}
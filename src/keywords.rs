@@ -0,0 +1,107 @@
+use std::collections::{HashMap, HashSet};
+
+// Skills and tools recognised even when mentioned only once; multi-word terms match as phrases
+const SKILLS: &[&str] = &[
+    "rust", "python", "java", "javascript", "typescript", "golang", "c", "c++", "c#", "ruby",
+    "php", "swift", "kotlin", "scala", "haskell", "elixir", "sql", "nosql", "bash",
+    "react", "angular", "vue", "svelte", "node.js", "django", "flask", "rails", "spring", ".net",
+    "html", "css", "graphql", "rest", "grpc", "websockets",
+    "aws", "gcp", "azure", "docker", "kubernetes", "terraform", "ansible", "linux", "git",
+    "ci/cd", "jenkins", "github actions", "postgres", "postgresql", "mysql", "sqlite", "mongodb",
+    "redis", "kafka", "rabbitmq", "elasticsearch", "spark", "hadoop", "airflow", "snowflake",
+    "machine learning", "deep learning", "data science", "data engineering", "nlp",
+    "computer vision", "pytorch", "tensorflow", "pandas", "numpy", "llm",
+    "distributed systems", "microservices", "embedded", "networking", "security", "cryptography",
+    "concurrency", "performance", "observability", "testing", "tdd", "agile", "scrum",
+    "system design", "api design", "mobile", "ios", "android", "frontend", "backend",
+    "full stack", "devops", "sre", "leadership", "mentoring",
+];
+
+// Common English and job-posting filler that says nothing about the role's requirements
+const STOPWORDS: &[&str] = &[
+    "a", "about", "above", "across", "after", "all", "also", "an", "and", "any", "are", "as", "at",
+    "be", "been", "being", "both", "but", "by", "can", "could", "do", "does", "each", "etc", "for",
+    "from", "has", "have", "help", "how", "if", "in", "into", "is", "it", "its", "just", "like",
+    "make", "may", "more", "most", "must", "new", "not", "of", "on", "one", "or", "other", "our",
+    "out", "over", "own", "part", "per", "plus", "role", "should", "so", "some", "such", "than",
+    "that", "the", "their", "them", "there", "these", "they", "this", "those", "through", "to",
+    "up", "us", "use", "using", "very", "was", "we", "well", "were", "what", "when", "where",
+    "which", "while", "who", "why", "will", "with", "within", "work", "working", "would", "you",
+    "your", "years", "year", "experience", "team", "teams", "ability", "strong", "including",
+    "company", "job", "candidate", "candidates", "opportunity",
+];
+
+// Frequent terms beyond the skill list are included up to this many
+const MAX_FREQUENT_TERMS: usize = 15;
+
+/// Which of a posting's keywords a resume covers
+#[derive(Debug, Clone, Default)]
+pub struct KeywordReport {
+    pub matched: Vec<String>,
+    pub missing: Vec<String>,
+}
+
+impl KeywordReport {
+    // Share of the posting's keywords found in the resume, 0-100
+    pub fn coverage(&self) -> u32 {
+        let total = self.matched.len() + self.missing.len();
+        if total == 0 {
+            return 0;
+        }
+        (self.matched.len() * 100 / total) as u32
+    }
+}
+
+// Lowercase words, keeping characters that belong to names like "c++", "c#" and ".net"
+fn tokens(text: &str) -> Vec<String> {
+    text.split(|c: char| !(c.is_alphanumeric() || matches!(c, '+' | '#' | '.' | '/')))
+        // Sentence punctuation is dropped, but a leading dot stays for ".net"
+        .map(|word| word.trim_end_matches(['.', '/']).trim_start_matches('/').to_lowercase())
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+fn contains_phrase(tokens: &[String], phrase: &str) -> bool {
+    let words: Vec<&str> = phrase.split(' ').collect();
+    tokens
+        .windows(words.len())
+        .any(|window| window.iter().zip(&words).all(|(token, word)| token == word))
+}
+
+/// Skills the posting mentions, followed by its most repeated other terms
+pub fn extract_keywords(description: &str) -> Vec<String> {
+    let tokens = tokens(description);
+    let mut keywords: Vec<String> = SKILLS
+        .iter()
+        .filter(|skill| contains_phrase(&tokens, skill))
+        .map(|skill| skill.to_string())
+        .collect();
+
+    let known: HashSet<&str> = SKILLS.iter().flat_map(|skill| skill.split(' ')).collect();
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for token in &tokens {
+        let is_term = token.len() >= 3
+            && !token.chars().all(|c| c.is_ascii_digit())
+            && !STOPWORDS.contains(&token.as_str())
+            && !known.contains(token.as_str());
+        if is_term {
+            *counts.entry(token.as_str()).or_default() += 1;
+        }
+    }
+
+    // Only terms repeated in the posting are likely to be requirements
+    let mut frequent: Vec<(&str, usize)> = counts.into_iter().filter(|(_, count)| *count >= 2).collect();
+    frequent.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+    keywords.extend(frequent.into_iter().take(MAX_FREQUENT_TERMS).map(|(term, _)| term.to_string()));
+
+    keywords
+}
+
+/// Compare a posting's keywords against resume text
+pub fn compare(description: &str, resume: &str) -> KeywordReport {
+    let resume_tokens = tokens(resume);
+    let (matched, missing) = extract_keywords(description)
+        .into_iter()
+        .partition(|keyword| contains_phrase(&resume_tokens, keyword));
+    KeywordReport { matched, missing }
+}
@@ -1,26 +1,41 @@
+mod activity;
+mod calendar;
+mod company;
 mod data;
+mod description;
+mod documents;
+mod duplicates;
+mod goals;
+mod icalendar;
+mod keywords;
 mod message;
+mod metrics;
+mod pipeline;
+mod search;
 mod state;
+mod templates;
 mod theme;
 mod ui;
 mod update;
 mod storage;
+mod sync;
 
-use iced::{application, Size, Theme, Task};
+use iced::{application, Size, Theme};
 use state::JobTracker;
 use ui::view;
-use update::update;
+use update::{scan_duplicates, subscription, update};
 
 fn main() -> iced::Result {
     // Using the new application API with Wayland compatibility
     application("Job Application Tracker", update, view)
+        .subscription(subscription)
         .theme(|_| Theme::Dark)
         .window_size(Size::new(1100.0, 700.0))
         .antialiasing(true)
         .run_with(|| {
-            (
-                JobTracker::new(), // Fixed - no arguments needed
-                Task::none(),
-            )
+            let state = JobTracker::new(); // Fixed - no arguments needed
+            // Look for duplicates in the loaded data without delaying startup
+            let scan = scan_duplicates(&state);
+            (state, scan)
         })
 }
@@ -5,13 +5,59 @@ pub enum Message {
     CompanyChanged(String),
     PositionChanged(String),
     DateChanged(String),
-    NotesChanged(String),
+    NotesEdited(iced::widget::text_editor::Action),
+    ToggleNotes(usize),
+    SetPriority(usize, u8),
+    TogglePin(usize),
+    DescriptionEdited(iced::widget::text_editor::Action),
+    DescriptionPathChanged(String),
+    ImportDescription,
+    DescriptionImported(Result<String, String>),
+    ToggleDescription(usize),
+    KeywordResumePicked(String),
+    AnalyzeKeywords(usize),
+    KeywordReportReady(usize, Result<crate::keywords::KeywordReport, String>),
     UrlChanged(String),
-    StatusSelected(crate::data::JobStatus),
+    StatusSelected(String),
     AddJob,
     ResetForm,
     StartEditing(usize),
     SaveEdit,
+    TransitionNoteChanged(String),
+    ReasonStageSelected(String),
+    ReasonCategorySelected(crate::data::ReasonCategory),
+    ReasonDetailsChanged(String),
+    ToggleReasonStats,
+    ToggleActivity,
+    ToggleTiming,
+    // Application sources
+    SourceChanged(String),
+    FilterSourceChanged(crate::data::SourceFilter),
+    ToggleSources,
+    NewSourceChanged(String),
+    AddSource,
+    RemoveSource(usize),
+    // Location, work mode and visa sponsorship
+    CityChanged(String),
+    RegionChanged(String),
+    CountryChanged(String),
+    WorkModeSelected(crate::data::WorkMode),
+    SponsorshipSelected(crate::data::Sponsorship),
+    FilterLocationChanged(crate::data::LocationFilter),
+    FilterWorkModeChanged(crate::data::WorkModeFilter),
+    ToggleSponsorshipFilter,
+    GroupByChanged(crate::data::GroupBy),
+    // Goals and the networking log
+    ToggleGoals,
+    NewGoalMetricSelected(crate::goals::GoalMetric),
+    NewGoalPeriodSelected(crate::goals::GoalPeriod),
+    NewGoalTargetChanged(String),
+    AddGoal,
+    RemoveGoal(usize),
+    NetworkingNoteChanged(String),
+    LogNetworkingContact,
+    RemoveNetworkingEntry(usize),
+    ForceTransitionToggled(bool),
     CancelEdit,
     DeleteJob(usize),
     SaveData,
@@ -21,7 +67,126 @@ pub enum Message {
     ErrorDismissed,
     // New filter-related messages
     SearchQueryChanged(String),
-    FilterStatusChanged(crate::data::JobStatus),
+    ToggleRelevanceSort,
+    FilterStatusChanged(crate::data::StatusFilter),
+    DateFromChanged(String),
+    DateToChanged(String),
+    StaleDaysChanged(String),
+    DatePresetSelected(crate::state::DatePreset),
     ClearFilters,
     SortBy(crate::state::SortColumn),
+    ModifiersChanged(iced::keyboard::Modifiers),
+    // Pipeline editor messages
+    TogglePipelineEditor,
+    StageNameChanged(usize, String),
+    StageNameSubmitted,
+    StageColorChanged(usize, String),
+    StageTerminalToggled(usize, bool),
+    StageInterviewToggled(usize, bool),
+    StageOutcomeSelected(usize, crate::pipeline::StageOutcome),
+    MoveStage(usize, bool),  // true moves the stage up
+    ToggleStageRules(usize),
+    StageAnyTransitionToggled(usize, bool),
+    StageTransitionToggled(usize, String),
+    StageRequirementToggled(usize, crate::pipeline::RequiredField, bool),
+    AddStage,
+    RemoveStage(usize),
+    PipelinePresetSelected(crate::pipeline::PipelinePreset),
+    // Saved view messages
+    ApplyView(String),
+    ViewNameChanged(String),
+    SaveCurrentView,
+    DeleteView(String),
+    // Duplicate detection and merging
+    DuplicatesFound(Vec<(usize, usize)>),
+    ToggleDuplicates,
+    StartMerge(usize, usize),
+    MergeFieldPicked(crate::duplicates::MergeField, bool),  // true takes the right-hand value
+    ConfirmMerge,
+    CancelMerge,
+    // Company registry messages
+    CompanySuggestionPicked(String),
+    ToggleCompanies,
+    NewCompanyNameChanged(String),
+    AddCompany,
+    RegisterAllCompanies,
+    RemoveCompany(usize),
+    OpenCompany(String),
+    RegisterCompany(String),
+    CloseCompany,
+    CompanyRenameChanged(String),
+    RenameCompany(usize),
+    CompanyWebsiteChanged(usize, String),
+    CompanyIndustryChanged(usize, String),
+    CompanySizeSelected(usize, crate::company::CompanySize),
+    CompanyNotesChanged(usize, String),
+    AliasInputChanged(String),
+    AddAlias(usize),
+    RemoveAlias(usize, usize),
+    ContactNameChanged(String),
+    ContactRoleChanged(String),
+    ContactEmailChanged(String),
+    AddContact(usize),
+    RemoveContact(usize, usize),
+    // Document library messages
+    ToggleDocuments,
+    DocumentPathChanged(String),
+    DocumentNameChanged(String),
+    DocumentKindSelected(crate::documents::DocumentKind),
+    AddDocument,
+    RemoveDocument(String),
+    OpenDocument(String),
+    DocumentPicked(crate::documents::DocumentKind, Option<String>),  // None clears the choice
+    // Template library messages
+    ToggleTemplates,
+    EditTemplate(usize),
+    NewTemplate,
+    AddStarterTemplates,
+    DeleteTemplate(usize),
+    TemplateNameChanged(String),
+    TemplateKindSelected(crate::templates::TemplateKind),
+    TemplateSubjectChanged(String),
+    TemplateBodyEdited(iced::widget::text_editor::Action),
+    ToggleTemplatePreview(usize),
+    PreviewTemplatePicked(usize),
+    PreviewContactPicked(usize),
+    CopyRendered,
+    ExportRendered(bool),  // true writes an .eml draft instead of plain text
+    // Scheduled events and calendar export
+    EventKindSelected(crate::data::EventKind),
+    EventDateChanged(String),
+    EventTimeChanged(String),
+    EventTitleChanged(String),
+    AddEvent,
+    RemoveEvent(String),
+    // Deadlines
+    DeadlineKindSelected(crate::data::DeadlineKind),
+    DeadlineDateChanged(String),
+    DeadlineTimeChanged(String),
+    DeadlineNoteChanged(String),
+    AddDeadline,
+    RemoveDeadline(String),
+    ExportCalendar,
+    ToggleLiveCalendar,
+    // Calendar view
+    SetViewMode(crate::calendar::ViewMode),
+    // Archive and trash
+    SetListScope(crate::state::ListScope),
+    ToggleArchived(usize),
+    ArchiveClosed,
+    RestoreJob(usize),
+    PurgeJob(usize),
+    EmptyTrash,
+    TrashDaysChanged(String),
+    TrashDaysSubmitted,
+    PurgeExpiredTrash,  // Periodic
+    // Changes made to the data file outside the app
+    CheckDataFile,
+    ResolveConflict(usize, bool),  // Conflict index, keep my version
+    ResolveAllConflicts(bool),
+    ApplyResolution,
+    ShiftCalendarMonth(i32),
+    CalendarToday,
+    OpenDay(chrono::NaiveDate),
+    ClearDayFilter,
 }
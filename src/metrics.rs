@@ -0,0 +1,219 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use std::collections::BTreeMap;
+
+use crate::data::{JobApplication, StatusChange};
+use crate::pipeline::{Pipeline, StageOutcome};
+
+// Active applications sitting in one stage this long are shown as aging, then as stale
+pub const AGING_DAYS: i64 = 7;
+pub const STALE_DAYS: i64 = 21;
+
+fn changed_on(change: &StatusChange) -> Option<NaiveDate> {
+    NaiveDateTime::parse_from_str(&change.at, "%Y-%m-%d %H:%M:%S")
+        .ok()
+        .map(|at| at.date())
+}
+
+/// Day the application entered its current stage, from its history or else the application date
+pub fn stage_entered(job: &JobApplication) -> Option<NaiveDate> {
+    job.history
+        .iter()
+        .rev()
+        .find(|change| change.to == job.status)
+        .and_then(changed_on)
+        .or_else(|| job.applied_on())
+}
+
+pub fn days_in_stage(job: &JobApplication, today: NaiveDate) -> Option<i64> {
+    stage_entered(job).map(|entered| (today - entered).num_days().max(0))
+}
+
+/// Days from applying until the first change that counts as a response
+pub fn days_to_response(job: &JobApplication, pipeline: &Pipeline) -> Option<i64> {
+    let applied = job.applied_on().or_else(|| job.history.first().and_then(changed_on))?;
+    let responded = job
+        .history
+        .iter()
+        .filter(|change| change.from.is_some())
+        .find(|change| job.counts_as_response(&change.to, pipeline))
+        .and_then(changed_on)?;
+    Some((responded - applied).num_days().max(0))
+}
+
+/// Days from first reaching an interview stage until an offer or rejection
+pub fn days_to_decision(job: &JobApplication, pipeline: &Pipeline) -> Option<i64> {
+    let start = job.history.iter().position(|change| pipeline.is_interview(&change.to))?;
+    let interviewed = changed_on(&job.history[start])?;
+    let decided = job.history[start + 1..]
+        .iter()
+        .find(|change| {
+            matches!(
+                pipeline.outcome(&change.to),
+                StageOutcome::Offer | StageOutcome::Accepted | StageOutcome::Rejected
+            )
+        })
+        .and_then(changed_on)?;
+    Some((decided - interviewed).num_days().max(0))
+}
+
+/// Median and 90th percentile of a set of durations in days
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Summary {
+    pub count: usize,
+    pub median: f32,
+    pub p90: i64,
+}
+
+pub fn summarize(mut days: Vec<i64>) -> Option<Summary> {
+    if days.is_empty() {
+        return None;
+    }
+    days.sort_unstable();
+
+    let n = days.len();
+    let median = if n.is_multiple_of(2) {
+        (days[n / 2 - 1] + days[n / 2]) as f32 / 2.0
+    } else {
+        days[n / 2] as f32
+    };
+    // Nearest-rank percentile
+    let p90 = days[(n * 9).div_ceil(10) - 1];
+
+    Some(Summary { count: n, median, p90 })
+}
+
+/// Response and decision times for one company, or for every application
+#[derive(Debug, Clone, Default)]
+pub struct Timing {
+    pub response: Option<Summary>,
+    pub decision: Option<Summary>,
+}
+
+fn timing<'a>(jobs: impl Iterator<Item = &'a JobApplication> + Clone, pipeline: &Pipeline) -> Timing {
+    Timing {
+        response: summarize(jobs.clone().filter_map(|job| days_to_response(job, pipeline)).collect()),
+        decision: summarize(jobs.filter_map(|job| days_to_decision(job, pipeline)).collect()),
+    }
+}
+
+/// Overall timing, and per company for companies with at least one measurement
+pub fn timing_report(jobs: &[JobApplication], pipeline: &Pipeline) -> (Timing, Vec<(String, Timing)>) {
+    let mut by_company: BTreeMap<String, Vec<&JobApplication>> = BTreeMap::new();
+    for job in jobs {
+        by_company.entry(job.company.trim().to_string()).or_default().push(job);
+    }
+
+    let companies = by_company
+        .into_iter()
+        .map(|(company, jobs)| (company, timing(jobs.into_iter(), pipeline)))
+        .filter(|(_, timing)| timing.response.is_some() || timing.decision.is_some())
+        .collect();
+
+    (timing(jobs.iter(), pipeline), companies)
+}
+
+// Whether the application was ever in a stage matching the predicate
+fn reached(job: &JobApplication, stage: impl Fn(&str) -> bool) -> bool {
+    std::iter::once(&job.status)
+        .chain(job.history.iter().map(|change| &change.to))
+        .any(|name| stage(name))
+}
+
+/// How far applications from one source got
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SourceCounts {
+    pub source: String,  // Empty for applications without one
+    pub applied: usize,
+    pub interviews: usize,
+    pub offers: usize,
+}
+
+impl SourceCounts {
+    pub fn interview_rate(&self) -> f32 {
+        rate(self.interviews, self.applied)
+    }
+
+    pub fn offer_rate(&self) -> f32 {
+        rate(self.offers, self.applied)
+    }
+}
+
+fn rate(part: usize, total: usize) -> f32 {
+    if total == 0 { 0.0 } else { part as f32 / total as f32 * 100.0 }
+}
+
+/// Applied, interview and offer counts per source, busiest first
+pub fn source_report(jobs: &[JobApplication], pipeline: &Pipeline) -> Vec<SourceCounts> {
+    let mut by_source: BTreeMap<String, SourceCounts> = BTreeMap::new();
+    for job in jobs {
+        let source = job.source.trim();
+        let counts = by_source.entry(source.to_lowercase()).or_insert_with(|| SourceCounts {
+            source: source.to_string(),
+            ..Default::default()
+        });
+
+        counts.applied += 1;
+        if reached(job, |stage| pipeline.is_interview(stage)) {
+            counts.interviews += 1;
+        }
+        if reached(job, |stage| {
+            matches!(pipeline.outcome(stage), StageOutcome::Offer | StageOutcome::Accepted)
+        }) {
+            counts.offers += 1;
+        }
+    }
+
+    let mut report: Vec<SourceCounts> = by_source.into_values().collect();
+    report.sort_by(|a, b| b.applied.cmp(&a.applied).then_with(|| a.source.cmp(&b.source)));
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(from: Option<&str>, to: &str, at: &str) -> StatusChange {
+        StatusChange {
+            from: from.map(str::to_string),
+            to: to.to_string(),
+            at: at.to_string(),
+            note: String::new(),
+            forced: false,
+        }
+    }
+
+    fn date(value: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn stage_entered_follows_the_latest_status_change() {
+        let mut job = JobApplication {
+            status: "Applied".to_string(),
+            date_applied: "2026-03-01".to_string(),
+            history: vec![change(None, "Applied", "2026-03-01 09:00:00")],
+            ..Default::default()
+        };
+        assert_eq!(stage_entered(&job), Some(date("2026-03-01")));
+
+        job.status = "Interview".to_string();
+        job.history.push(change(Some("Applied"), "Interview", "2026-03-10 14:30:00"));
+        assert_eq!(stage_entered(&job), Some(date("2026-03-10")));
+        assert_eq!(days_in_stage(&job, date("2026-03-15")), Some(5));
+
+        // Re-entering a stage counts from the most recent entry
+        job.history.push(change(Some("Interview"), "Rejected", "2026-03-12 10:00:00"));
+        job.history.push(change(Some("Rejected"), "Interview", "2026-03-20 10:00:00"));
+        assert_eq!(stage_entered(&job), Some(date("2026-03-20")));
+    }
+
+    #[test]
+    fn stage_entered_falls_back_to_the_application_date() {
+        let job = JobApplication {
+            status: "Interview".to_string(),
+            date_applied: "2026-03-01".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(stage_entered(&job), Some(date("2026-03-01")));
+    }
+}
@@ -0,0 +1,345 @@
+use iced::Color;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::data::JobApplication;
+use crate::theme::{color_to_hex, parse_hex_color};
+
+/// What reaching a stage means for the application, used for styling and stats
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum StageOutcome {
+    #[default]
+    Pending,
+    Offer,
+    Accepted,
+    Rejected,
+    Withdrawn,
+}
+
+impl StageOutcome {
+    pub const ALL: [StageOutcome; 5] = [
+        StageOutcome::Pending,
+        StageOutcome::Offer,
+        StageOutcome::Accepted,
+        StageOutcome::Rejected,
+        StageOutcome::Withdrawn,
+    ];
+
+    // Closed without a hire, shown dimmed in the list
+    pub fn is_negative(&self) -> bool {
+        matches!(self, StageOutcome::Rejected | StageOutcome::Withdrawn)
+    }
+}
+
+impl fmt::Display for StageOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                StageOutcome::Pending => "In progress",
+                StageOutcome::Offer => "Offer",
+                StageOutcome::Accepted => "Accepted",
+                StageOutcome::Rejected => "Rejected",
+                StageOutcome::Withdrawn => "Withdrawn",
+            }
+        )
+    }
+}
+
+/// Information that must be filled in before moving into a stage
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RequiredField {
+    Note,  // Note on the status change itself
+    Url,
+    Reason,  // Structured closure reason
+}
+
+impl RequiredField {
+    pub const ALL: [RequiredField; 3] = [RequiredField::Note, RequiredField::Url, RequiredField::Reason];
+}
+
+impl fmt::Display for RequiredField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                RequiredField::Note => "Change note",
+                RequiredField::Url => "URL",
+                RequiredField::Reason => "Closure reason",
+            }
+        )
+    }
+}
+
+/// One step of a hiring pipeline
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Stage {
+    pub name: String,
+    pub color: String,  // "#rrggbb"
+    #[serde(default)]
+    pub terminal: bool,  // No further movement expected once here
+    #[serde(default)]
+    pub outcome: StageOutcome,
+    // Stages an application may move to next; None allows any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_next: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub requires: Vec<RequiredField>,
+    // Reaching this stage counts as getting an interview in the reports; None for stages saved
+    // before this could be set, which fall back to their name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interview: Option<bool>,
+}
+
+impl Stage {
+    pub fn new(name: &str, color: &str, terminal: bool, outcome: StageOutcome) -> Self {
+        Self {
+            name: name.to_string(),
+            color: color.to_string(),
+            terminal,
+            outcome,
+            allowed_next: None,
+            requires: Vec::new(),
+            interview: Some(false),
+        }
+    }
+
+    pub fn with_interview(mut self) -> Self {
+        self.interview = Some(true);
+        self
+    }
+
+    pub fn is_interview(&self) -> bool {
+        self.interview
+            .unwrap_or_else(|| self.name.to_lowercase().contains("interview"))
+    }
+
+    pub fn allows(&self, next: &str) -> bool {
+        self.allowed_next
+            .as_ref()
+            .is_none_or(|allowed| allowed.iter().any(|name| name == next))
+    }
+
+    pub fn color(&self) -> Color {
+        parse_hex_color(&self.color).unwrap_or(Color::from_rgb(0.5, 0.5, 0.5))
+    }
+}
+
+/// Ordered list of stages an application moves through
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Pipeline {
+    pub stages: Vec<Stage>,
+}
+
+impl Default for Pipeline {
+    // Mirrors the fixed statuses used before pipelines were configurable,
+    // so older data files map onto it unchanged
+    fn default() -> Self {
+        PipelinePreset::Industry.pipeline()
+    }
+}
+
+impl Pipeline {
+    pub fn stage(&self, name: &str) -> Option<&Stage> {
+        self.stages.iter().find(|stage| stage.name == name)
+    }
+
+    // Position in the pipeline; unknown stages sort last
+    pub fn rank(&self, name: &str) -> usize {
+        self.stages
+            .iter()
+            .position(|stage| stage.name == name)
+            .unwrap_or(self.stages.len())
+    }
+
+    pub fn outcome(&self, name: &str) -> StageOutcome {
+        self.stage(name).map(|stage| stage.outcome).unwrap_or_default()
+    }
+
+    pub fn is_interview(&self, name: &str) -> bool {
+        self.stage(name).is_some_and(Stage::is_interview)
+    }
+
+    pub fn is_active(&self, name: &str) -> bool {
+        self.stage(name).is_none_or(|stage| !stage.terminal)
+    }
+
+    pub fn color(&self, name: &str) -> Color {
+        self.stage(name)
+            .map(Stage::color)
+            .unwrap_or(Color::from_rgb(0.5, 0.5, 0.5))
+    }
+
+    pub fn names(&self) -> Vec<String> {
+        self.stages.iter().map(|stage| stage.name.clone()).collect()
+    }
+
+    /// Check a status change against the rules of the stage being left
+    pub fn validate_transition(&self, from: &str, to: &str) -> Result<(), String> {
+        if from == to {
+            return Ok(());
+        }
+        match self.stage(from) {
+            Some(stage) if !stage.allows(to) => {
+                Err(format!("\"{}\" cannot move to \"{}\"", from, to))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Forward-only rules: open stages move to later stages or drop out at any point,
+    /// only offers lead to acceptance, and terminal stages are final
+    pub fn with_default_rules(mut self) -> Self {
+        let stages = self.stages.clone();
+        for (i, stage) in self.stages.iter_mut().enumerate() {
+            let next = if stage.terminal {
+                Vec::new()
+            } else {
+                stages
+                    .iter()
+                    .enumerate()
+                    .filter(|(j, next)| {
+                        let drop_out = next.outcome.is_negative();
+                        let forward = *j > i
+                            && (next.outcome != StageOutcome::Accepted || stage.outcome == StageOutcome::Offer);
+                        *j != i && (drop_out || forward)
+                    })
+                    .map(|(_, next)| next.name.clone())
+                    .collect()
+            };
+            stage.allowed_next = Some(next);
+
+            // Closing an application should say why
+            if stage.outcome.is_negative() && !stage.requires.contains(&RequiredField::Reason) {
+                stage.requires.push(RequiredField::Reason);
+            }
+        }
+        self
+    }
+
+    /// Append any statuses used by jobs but missing from the pipeline so no data is lost
+    pub fn adopt_statuses(&mut self, jobs: &[JobApplication]) {
+        for job in jobs {
+            if self.stage(&job.status).is_none() {
+                self.stages.push(Stage::new(
+                    &job.status,
+                    &color_to_hex(Color::from_rgb(0.5, 0.5, 0.5)),
+                    false,
+                    StageOutcome::Pending,
+                ));
+            }
+        }
+    }
+}
+
+/// Starting points for common kinds of job search
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PipelinePreset {
+    Industry,
+    Academia,
+    Government,
+    Freelance,
+}
+
+impl PipelinePreset {
+    pub const ALL: [PipelinePreset; 4] = [
+        PipelinePreset::Industry,
+        PipelinePreset::Academia,
+        PipelinePreset::Government,
+        PipelinePreset::Freelance,
+    ];
+
+    pub fn pipeline(&self) -> Pipeline {
+        use StageOutcome::*;
+
+        let stages = match self {
+            PipelinePreset::Industry => vec![
+                Stage::new("Applied", "#3882c7", false, Pending),
+                Stage::new("OA", "#e69e00", false, Pending),
+                Stage::new("Interview", "#21bc83", false, Pending).with_interview(),
+                Stage::new("Offer", "#9b59b6", false, Offer),
+                Stage::new("Accepted", "#21bc83", true, Accepted),
+                Stage::new("Rejected", "#f24444", true, Rejected),
+                Stage::new("Withdrawn", "#808080", true, Withdrawn),
+            ],
+            PipelinePreset::Academia => vec![
+                Stage::new("Applied", "#3882c7", false, Pending),
+                Stage::new("Longlisted", "#5dade2", false, Pending),
+                Stage::new("Video Interview", "#e69e00", false, Pending).with_interview(),
+                Stage::new("Campus Visit", "#21bc83", false, Pending).with_interview(),
+                Stage::new("Offer", "#9b59b6", false, Offer),
+                Stage::new("Accepted", "#21bc83", true, Accepted),
+                Stage::new("Rejected", "#f24444", true, Rejected),
+                Stage::new("Withdrawn", "#808080", true, Withdrawn),
+            ],
+            PipelinePreset::Government => vec![
+                Stage::new("Applied", "#3882c7", false, Pending),
+                Stage::new("Qualified", "#5dade2", false, Pending),
+                Stage::new("Referred", "#e69e00", false, Pending),
+                Stage::new("Interview", "#21bc83", false, Pending).with_interview(),
+                Stage::new("Tentative Offer", "#9b59b6", false, Offer),
+                Stage::new("Hired", "#21bc83", true, Accepted),
+                Stage::new("Not Selected", "#f24444", true, Rejected),
+                Stage::new("Withdrawn", "#808080", true, Withdrawn),
+            ],
+            PipelinePreset::Freelance => vec![
+                Stage::new("Pitched", "#3882c7", false, Pending),
+                Stage::new("Proposal Sent", "#e69e00", false, Pending),
+                Stage::new("Client Call", "#21bc83", false, Pending).with_interview(),
+                Stage::new("Negotiating", "#9b59b6", false, Offer),
+                Stage::new("Contract Signed", "#21bc83", true, Accepted),
+                Stage::new("Lost", "#f24444", true, Rejected),
+                Stage::new("Declined", "#808080", true, Withdrawn),
+            ],
+        };
+
+        Pipeline { stages }.with_default_rules()
+    }
+}
+
+impl fmt::Display for PipelinePreset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                PipelinePreset::Industry => "Industry",
+                PipelinePreset::Academia => "Academia",
+                PipelinePreset::Government => "Government",
+                PipelinePreset::Freelance => "Freelance",
+            }
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_rules_block_moving_backwards() {
+        let pipeline = Pipeline::default();
+        assert!(pipeline.validate_transition("Applied", "Interview").is_ok());
+        assert!(pipeline.validate_transition("Interview", "Withdrawn").is_ok());
+        assert!(pipeline.validate_transition("Applied", "Accepted").is_err());
+        assert!(pipeline.validate_transition("Interview", "Applied").is_err());
+        assert!(pipeline.validate_transition("Rejected", "Interview").is_err());
+    }
+
+    #[test]
+    fn forced_override_is_recorded() {
+        let pipeline = Pipeline::default();
+        let mut job = JobApplication { status: "Rejected".to_string(), ..Default::default() };
+        assert!(pipeline.validate_transition(&job.status, "Interview").is_err());
+
+        job.status = "Interview".to_string();
+        job.record_status_change(Some("Rejected".to_string()), " recruiter reopened the role ", true);
+        let change = job.history.last().unwrap();
+        assert_eq!(change.from.as_deref(), Some("Rejected"));
+        assert_eq!(change.to, "Interview");
+        assert_eq!(change.note, "recruiter reopened the role");
+        assert!(change.forced);
+    }
+}
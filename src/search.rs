@@ -0,0 +1,311 @@
+use std::ops::Range;
+
+use crate::data::JobApplication;
+
+// Field weights: a hit in the company name matters more than one buried in notes
+const COMPANY_WEIGHT: u32 = 3;
+const POSITION_WEIGHT: u32 = 2;
+const NOTES_WEIGHT: u32 = 1;
+const DESCRIPTION_WEIGHT: u32 = 1;
+
+/// A search query split into lowercase terms, parsed once per filter pass
+#[derive(Debug, Clone)]
+pub struct SearchQuery {
+    terms: Vec<Vec<char>>,
+}
+
+/// Byte ranges of matched text in each searchable field
+#[derive(Debug, Clone, Default)]
+pub struct Highlights {
+    pub company: Vec<Range<usize>>,
+    pub position: Vec<Range<usize>>,
+    pub notes: Vec<Range<usize>>,
+    pub description: Vec<Range<usize>>,
+}
+
+/// Result of matching a query against one application
+#[derive(Debug, Clone, Default)]
+pub struct JobMatch {
+    pub score: u32,
+    pub highlights: Highlights,
+}
+
+struct TermMatch {
+    score: u32,
+    ranges: Vec<Range<usize>>,
+}
+
+impl SearchQuery {
+    pub fn new(query: &str) -> Self {
+        Self {
+            terms: query
+                .split_whitespace()
+                .map(|term| term.chars().flat_map(char::to_lowercase).collect())
+                .collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Every term has to match at least one field for the job to match
+    pub fn match_job(&self, job: &JobApplication) -> Option<JobMatch> {
+        let company = LoweredText::new(&job.company);
+        let position = LoweredText::new(&job.position);
+        let notes = LoweredText::new(&job.notes);
+        let description = LoweredText::new(&job.description);
+
+        let mut result = JobMatch::default();
+        for term in &self.terms {
+            let mut matched = false;
+
+            for (field, weight, ranges) in [
+                (&company, COMPANY_WEIGHT, &mut result.highlights.company),
+                (&position, POSITION_WEIGHT, &mut result.highlights.position),
+                (&notes, NOTES_WEIGHT, &mut result.highlights.notes),
+                (&description, DESCRIPTION_WEIGHT, &mut result.highlights.description),
+            ] {
+                if let Some(term_match) = field.match_term(term) {
+                    result.score += term_match.score * weight;
+                    ranges.extend(term_match.ranges);
+                    matched = true;
+                }
+            }
+
+            if !matched {
+                return None;
+            }
+        }
+
+        for ranges in [
+            &mut result.highlights.company,
+            &mut result.highlights.position,
+            &mut result.highlights.notes,
+            &mut result.highlights.description,
+        ] {
+            merge_ranges(ranges);
+        }
+
+        Some(result)
+    }
+}
+
+/// Lowercased characters alongside their byte offsets in the original text
+struct LoweredText {
+    chars: Vec<char>,
+    offsets: Vec<usize>,
+    len: usize,
+}
+
+impl LoweredText {
+    fn new(text: &str) -> Self {
+        let mut chars = Vec::with_capacity(text.len());
+        let mut offsets = Vec::with_capacity(text.len());
+        for (offset, c) in text.char_indices() {
+            // Multi-char lowercase forms keep only their first char so offsets stay aligned
+            chars.push(c.to_lowercase().next().unwrap_or(c));
+            offsets.push(offset);
+        }
+        Self { chars, offsets, len: text.len() }
+    }
+
+    fn byte_range(&self, start: usize, end: usize) -> Range<usize> {
+        let to_byte = |i: usize| self.offsets.get(i).copied().unwrap_or(self.len);
+        to_byte(start)..to_byte(end)
+    }
+
+    fn is_word_start(&self, i: usize) -> bool {
+        i == 0 || !self.chars[i - 1].is_alphanumeric()
+    }
+
+    // Try exact substring first, then typo-tolerant word matches, then abbreviations
+    fn match_term(&self, term: &[char]) -> Option<TermMatch> {
+        self.substring_match(term)
+            .or_else(|| self.typo_match(term))
+            .or_else(|| self.subsequence_match(term))
+    }
+
+    fn substring_match(&self, term: &[char]) -> Option<TermMatch> {
+        if term.is_empty() || term.len() > self.chars.len() {
+            return None;
+        }
+
+        let start = (0..=self.chars.len() - term.len())
+            .find(|&i| self.chars[i..i + term.len()] == *term)?;
+        let bonus = if self.is_word_start(start) { 20 } else { 0 };
+
+        Some(TermMatch {
+            score: 100 + bonus,
+            ranges: vec![self.byte_range(start, start + term.len())],
+        })
+    }
+
+    // Compare the term against each word, allowing one typo per four characters
+    fn typo_match(&self, term: &[char]) -> Option<TermMatch> {
+        let allowed = (term.len() / 4).min(2);
+        if allowed == 0 {
+            return None;
+        }
+
+        let mut best: Option<(usize, usize, usize)> = None;
+        for (start, end) in self.words() {
+            let word = &self.chars[start..end];
+            // Also compare against the word's prefix so partially typed words still match
+            let prefix = &word[..word.len().min(term.len() + allowed)];
+            let distance = edit_distance(term, word).min(edit_distance(term, prefix));
+            if distance <= allowed && best.is_none_or(|(d, _, _)| distance < d) {
+                best = Some((distance, start, end));
+            }
+        }
+
+        best.map(|(distance, start, end)| TermMatch {
+            score: 60 - 15 * distance as u32,
+            ranges: vec![self.byte_range(start, end)],
+        })
+    }
+
+    // Characters in order within a short window, e.g. "swe" in "software engineer"
+    fn subsequence_match(&self, term: &[char]) -> Option<TermMatch> {
+        if term.len() < 2 {
+            return None;
+        }
+
+        let max_span = term.len() * 4;
+        (0..self.chars.len())
+            .filter(|&start| self.chars[start] == term[0] && self.is_word_start(start))
+            .find_map(|start| {
+                let mut positions = vec![start];
+                let mut i = start + 1;
+                // Scanning stops at the window's end, keeping long descriptions cheap
+                let end = self.chars.len().min(start + max_span);
+                for &c in &term[1..] {
+                    while i < end && self.chars[i] != c {
+                        i += 1;
+                    }
+                    if i >= end {
+                        return None;
+                    }
+                    positions.push(i);
+                    i += 1;
+                }
+
+                let span = i - start;
+                Some(TermMatch {
+                    score: 40u32.saturating_sub((span - term.len()) as u32 * 2).max(5),
+                    ranges: positions.into_iter().map(|p| self.byte_range(p, p + 1)).collect(),
+                })
+            })
+    }
+
+    fn words(&self) -> Vec<(usize, usize)> {
+        let mut words = Vec::new();
+        let mut start = None;
+        for (i, c) in self.chars.iter().enumerate() {
+            match (c.is_alphanumeric(), start) {
+                (true, None) => start = Some(i),
+                (false, Some(s)) => {
+                    words.push((s, i));
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+        if let Some(s) = start {
+            words.push((s, self.chars.len()));
+        }
+        words
+    }
+}
+
+pub fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, ca) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+fn merge_ranges(ranges: &mut Vec<Range<usize>>) {
+    ranges.sort_by_key(|r| r.start);
+    let mut merged: Vec<Range<usize>> = Vec::with_capacity(ranges.len());
+    for range in ranges.drain(..) {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+    *ranges = merged;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn job(company: &str, position: &str) -> JobApplication {
+        JobApplication {
+            company: company.to_string(),
+            position: position.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn lowered(text: &str) -> Vec<char> {
+        text.chars().collect()
+    }
+
+    #[test]
+    fn substring_outranks_typo_and_abbreviation() {
+        let text = LoweredText::new("Senior Software Engineer");
+        let exact = text.match_term(&lowered("software")).unwrap();
+        let typo = text.match_term(&lowered("sofware")).unwrap();
+        let abbreviation = text.match_term(&lowered("swe")).unwrap();
+        assert!(exact.score > typo.score);
+        assert!(typo.score > abbreviation.score);
+        assert_eq!(exact.ranges, vec![7..15]);
+    }
+
+    #[test]
+    fn subsequence_must_fit_in_window() {
+        let text = LoweredText::new("software engineer");
+        assert!(text.subsequence_match(&lowered("swe")).is_some());
+        // Characters present but spread further apart than the window allows
+        let spread = LoweredText::new(&format!("s{}we", "x".repeat(40)));
+        assert!(spread.subsequence_match(&lowered("swe")).is_none());
+    }
+
+    #[test]
+    fn every_term_must_match_some_field() {
+        let job = job("Acme", "Rust Developer");
+        assert!(SearchQuery::new("acme rust").match_job(&job).is_some());
+        assert!(SearchQuery::new("acme python").match_job(&job).is_none());
+    }
+
+    #[test]
+    fn company_hits_weigh_more_than_position_hits() {
+        let in_company = SearchQuery::new("rust").match_job(&job("Rust Corp", "Developer")).unwrap();
+        let in_position = SearchQuery::new("rust").match_job(&job("Acme", "Rust Developer")).unwrap();
+        assert!(in_company.score > in_position.score);
+    }
+
+    #[test]
+    fn highlights_use_byte_offsets_of_the_original_text() {
+        let result = SearchQuery::new("café").match_job(&job("Ümlaut Café", "Barista")).unwrap();
+        assert_eq!(result.highlights.company, vec![8..13]);
+    }
+
+    #[test]
+    fn overlapping_ranges_merge() {
+        let mut ranges = vec![4..6, 0..2, 1..3];
+        merge_ranges(&mut ranges);
+        assert_eq!(ranges, vec![0..3, 4..6]);
+    }
+}
@@ -1,16 +1,125 @@
-use crate::data::{JobApplication, JobStatus};
+use crate::data::{
+    self, ClosureReason, Deadline, DeadlineKind, EventKind, GroupBy, JobApplication, Location, LocationFilter, ReasonCategory,
+    SavedView, ScheduledEvent, Settings, SourceFilter, Sponsorship, StatusFilter, TrackerData, TrashedJob, WorkMode,
+    WorkModeFilter,
+};
+use crate::calendar::{self, ViewMode};
+use crate::company::{self, Company, Contact};
+use crate::documents::{Document, DocumentKind};
+use crate::duplicates::{self, DuplicateReason, MergeField};
+use crate::goals::{Goal, NetworkingEntry};
+use crate::icalendar;
+use crate::keywords::KeywordReport;
+use crate::pipeline::{Pipeline, RequiredField, Stage};
+use crate::search::{Highlights, JobMatch, SearchQuery};
 use crate::storage;
-use chrono::Local;
+use crate::sync::Reconciled;
+use crate::templates::{self, Rendered, Template};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::time::SystemTime;
+use chrono::{Datelike, Duration, Local, NaiveDate, NaiveDateTime, TimeDelta};
+use iced::keyboard;
+use iced::widget::{markdown, text_editor};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default)]
 pub struct FormState {
     pub company: String,
     pub position: String,
     pub date_applied: String,
-    pub notes: String,
+    pub notes: text_editor::Content,  // Multi-line Markdown
     pub url: String,
-    pub status: Option<JobStatus>,
+    pub status: Option<String>,
     pub is_expanded: bool,
+    pub transition_note: String,  // Recorded in history when the status changes
+    pub force_transition: bool,
+    pub reason_stage: Option<String>,
+    pub reason_category: Option<ReasonCategory>,
+    pub reason_details: String,
+    pub description: text_editor::Content,  // Pasted or imported job description
+    pub description_path: String,  // Local .html, .pdf or .txt file to import from
+    pub importing_description: bool,
+    pub resume: Option<String>,  // Library document ids
+    pub cover_letter: Option<String>,
+    pub source: String,
+    pub events: Vec<ScheduledEvent>,
+    pub new_event: NewEvent,
+    pub city: String,
+    pub region: String,
+    pub country: String,
+    pub work_mode: Option<WorkMode>,
+    pub visa_sponsorship: Option<Sponsorship>,
+    pub deadlines: Vec<Deadline>,
+    pub new_deadline: NewDeadline,
+}
+
+/// Inputs for scheduling an interview or follow-up
+#[derive(Debug, Clone)]
+pub struct NewEvent {
+    pub kind: EventKind,
+    pub date: String,
+    pub time: String,
+    pub title: String,
+}
+
+impl Default for NewEvent {
+    fn default() -> Self {
+        Self {
+            kind: EventKind::Interview,
+            date: String::new(),
+            time: String::new(),
+            title: String::new(),
+        }
+    }
+}
+
+/// Inputs for adding a deadline
+#[derive(Debug, Clone)]
+pub struct NewDeadline {
+    pub kind: DeadlineKind,
+    pub date: String,
+    pub time: String,
+    pub note: String,
+}
+
+impl Default for NewDeadline {
+    fn default() -> Self {
+        Self {
+            kind: DeadlineKind::ApplicationClose,
+            date: String::new(),
+            time: String::new(),
+            note: String::new(),
+        }
+    }
+}
+
+impl NewDeadline {
+    // The deadline to add, once the date (and time, if given) parse
+    pub fn to_deadline(&self) -> Option<Deadline> {
+        let deadline = Deadline {
+            id: data::new_id(),
+            kind: self.kind,
+            date: self.date.trim().to_string(),
+            time: self.time.trim().to_string(),
+            note: self.note.trim().to_string(),
+        };
+        (deadline.on().is_some() && (deadline.time.is_empty() || deadline.at().is_some())).then_some(deadline)
+    }
+}
+
+impl NewEvent {
+    // The event to add, once the date (and time, if given) parse
+    pub fn to_event(&self) -> Option<ScheduledEvent> {
+        let event = ScheduledEvent {
+            id: data::new_id(),
+            kind: self.kind,
+            date: self.date.trim().to_string(),
+            time: self.time.trim().to_string(),
+            title: self.title.trim().to_string(),
+        };
+        (event.on().is_some() && (event.time.is_empty() || event.at().is_some())).then_some(event)
+    }
 }
 
 impl FormState {
@@ -28,14 +137,51 @@ impl FormState {
             return None;
         }
 
-        Some(JobApplication {
-            company: self.company.clone(),
-            position: self.position.clone(),
-            date_applied: self.date_applied.clone(),
-            status: self.status.unwrap(),
-            notes: self.notes.clone(),
-            url: if self.url.is_empty() { None } else { Some(self.url.clone()) },
-            last_updated: Some(Local::now().format("%Y-%m-%d %H:%M:%S").to_string()),
+        let mut job = JobApplication {
+            id: data::new_id(),
+            ..Default::default()
+        };
+        self.apply_to(&mut job);
+        Some(job)
+    }
+
+    // Copy the edited fields onto a job, leaving fields the form does not cover intact
+    pub fn apply_to(&self, job: &mut JobApplication) {
+        job.company = self.company.clone();
+        job.position = self.position.clone();
+        job.date_applied = self.date_applied.clone();
+        if let Some(status) = &self.status {
+            job.status = status.clone();
+        }
+        job.notes = self.notes.text().trim_end().to_string();
+        job.description = self.description.text().trim().to_string();
+        job.resume = self.resume.clone();
+        job.cover_letter = self.cover_letter.clone();
+        job.source = self.source.trim().to_string();
+        job.events = self.events.clone();
+        job.location = Location {
+            city: self.city.trim().to_string(),
+            region: self.region.trim().to_string(),
+            country: self.country.trim().to_string(),
+        };
+        job.work_mode = self.work_mode;
+        job.deadlines = self.deadlines.clone();
+        job.visa_sponsorship = self.visa_sponsorship;
+        job.url = if self.url.is_empty() { None } else { Some(self.url.clone()) };
+        job.closure_reason = self.reason_category.map(|category| ClosureReason {
+            stage_reached: self.reason_stage.clone().unwrap_or_default(),
+            category,
+            details: self.reason_details.trim().to_string(),
+        });
+        job.last_updated = Some(Local::now().format("%Y-%m-%d %H:%M:%S").to_string());
+    }
+
+    // First field the target stage requires that is still empty
+    pub fn missing_requirement(&self, stage: &Stage) -> Option<RequiredField> {
+        stage.requires.iter().copied().find(|field| match field {
+            RequiredField::Note => self.transition_note.trim().is_empty(),
+            RequiredField::Url => self.url.trim().is_empty(),
+            RequiredField::Reason => self.reason_category.is_none(),
         })
     }
 
@@ -45,79 +191,716 @@ impl FormState {
             company: job.company.clone(),
             position: job.position.clone(),
             date_applied: job.date_applied.clone(),
-            notes: job.notes.clone(),
+            notes: text_editor::Content::with_text(&job.notes),
+            description: text_editor::Content::with_text(&job.description),
+            resume: job.resume.clone(),
+            cover_letter: job.cover_letter.clone(),
+            source: job.source.clone(),
+            events: job.events.clone(),
+            city: job.location.city.clone(),
+            region: job.location.region.clone(),
+            country: job.location.country.clone(),
+            work_mode: job.work_mode,
+            visa_sponsorship: job.visa_sponsorship,
+            deadlines: job.deadlines.clone(),
             url: job.url.clone().unwrap_or_default(),
-            status: Some(job.status),
+            status: Some(job.status.clone()),
             is_expanded: true,
+            reason_stage: job.closure_reason.as_ref().map(|r| r.stage_reached.clone()),
+            reason_category: job.closure_reason.as_ref().map(|r| r.category),
+            reason_details: job.closure_reason.as_ref().map(|r| r.details.clone()).unwrap_or_default(),
+            ..Default::default()
         }
     }
 }
 
-#[derive(PartialEq, Eq, Clone, Copy)]
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
 pub enum SortOrder {
     Ascending,
     Descending,
-    None
 }
 
-impl Default for SortOrder {
-    fn default() -> Self {
-        SortOrder::None
-    }
-}
-
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
 pub enum SortColumn {
     Company,
+    Position,
     DateApplied,
-    None
+    Status,
+    LastUpdated,
+    Priority,
+    Deadline,  // Nearest upcoming deadline; applications without one sort last
+}
+
+/// One level of a multi-column sort
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct SortKey {
+    pub column: SortColumn,
+    pub order: SortOrder,
+}
+
+impl SortKey {
+    pub fn new(column: SortColumn, order: SortOrder) -> Self {
+        Self { column, order }
+    }
+
+    // `now` is taken once per sort so every comparison sees the same upcoming deadlines
+    pub fn compare(
+        &self,
+        a: &JobApplication,
+        b: &JobApplication,
+        pipeline: &Pipeline,
+        now: NaiveDateTime,
+    ) -> Ordering {
+        let ordered = |cmp: Ordering| match self.order {
+            SortOrder::Ascending => cmp,
+            SortOrder::Descending => cmp.reverse(),
+        };
+
+        let cmp = match self.column {
+            SortColumn::Company => cmp_ignore_case(&a.company, &b.company),
+            SortColumn::Position => cmp_ignore_case(&a.position, &b.position),
+            SortColumn::DateApplied => a.date_applied.cmp(&b.date_applied),
+            SortColumn::Status => pipeline.rank(&a.status).cmp(&pipeline.rank(&b.status)),
+            SortColumn::LastUpdated => a.last_updated.cmp(&b.last_updated),
+            SortColumn::Priority => a.priority.cmp(&b.priority),
+            SortColumn::Deadline => {
+                let (a, b) = (a.next_deadline(now), b.next_deadline(now));
+                // Applications without a deadline stay last whichever way the column is sorted
+                return a.is_none().cmp(&b.is_none()).then_with(|| ordered(a.cmp(&b)));
+            }
+        };
+
+        ordered(cmp)
+    }
+}
+
+fn parse_filter_date(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d").ok()
+}
+
+// Case-insensitive comparison without allocating lowercase copies
+fn cmp_ignore_case(a: &str, b: &str) -> Ordering {
+    a.chars()
+        .flat_map(char::to_lowercase)
+        .cmp(b.chars().flat_map(char::to_lowercase))
+}
+
+// Which applications the list shows
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ListScope {
+    #[default]
+    Applications,
+    Archive,
+    Trash,
+}
+
+// Quick ranges for the date-applied filter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DatePreset {
+    ThisWeek,
+    Last30Days,
+    ThisQuarter,
+}
+
+impl DatePreset {
+    pub const ALL: [DatePreset; 3] = [DatePreset::ThisWeek, DatePreset::Last30Days, DatePreset::ThisQuarter];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            DatePreset::ThisWeek => "This week",
+            DatePreset::Last30Days => "Last 30 days",
+            DatePreset::ThisQuarter => "This quarter",
+        }
+    }
+
+    // Inclusive date range ending today
+    pub fn range(&self, today: NaiveDate) -> (NaiveDate, NaiveDate) {
+        let from = match self {
+            DatePreset::ThisWeek => {
+                today - Duration::days(today.weekday().num_days_from_monday() as i64)
+            }
+            DatePreset::Last30Days => today - Duration::days(29),
+            DatePreset::ThisQuarter => {
+                let quarter_month = (today.month0() / 3) * 3 + 1;
+                NaiveDate::from_ymd_opt(today.year(), quarter_month, 1).unwrap_or(today)
+            }
+        };
+        (from, today)
+    }
+}
+
+/// Two applications being combined, and which fields come from the right-hand one
+#[derive(Debug, Clone)]
+pub struct MergeState {
+    pub left: usize,
+    pub right: usize,
+    pub take_right: Vec<MergeField>,
 }
 
-impl Default for SortColumn {
+/// The company whose page is shown
+#[derive(Debug, Clone, PartialEq)]
+pub enum CompanyView {
+    Registered(usize),  // Index into the registry
+    Unregistered(String),  // A name no registry entry covers; added only when asked to
+}
+
+/// Inputs on the company page that are only applied when confirmed
+#[derive(Debug, Clone, Default)]
+pub struct CompanyPageState {
+    pub rename: String,
+    pub alias: String,
+    pub contact: Contact,
+}
+
+/// Inputs for adding a file to the document library
+#[derive(Debug, Clone)]
+pub struct NewDocument {
+    pub path: String,
+    pub name: String,
+    pub kind: DocumentKind,
+}
+
+impl Default for NewDocument {
     fn default() -> Self {
-        SortColumn::None
+        Self {
+            path: String::new(),
+            name: String::new(),
+            kind: DocumentKind::Resume,
+        }
     }
 }
 
+/// A template being rendered for one application
+#[derive(Debug, Clone)]
+pub struct TemplatePreview {
+    pub job: usize,
+    pub template: Option<usize>,  // Index into the settings' templates
+    pub contact: Option<usize>,  // Contact at the job's company in the registry
+    pub exported: Option<String>,  // Path of the last file written
+}
+
 #[derive(Default)]
 pub struct JobTracker {
     pub jobs: Vec<JobApplication>,
+    pub pipeline: Pipeline,
+    pub companies: Vec<Company>,
+    pub documents: Vec<Document>,
+    pub networking: Vec<NetworkingEntry>,
+    pub trash: Vec<TrashedJob>,
+    // The data file as last loaded or saved, the base for merging in changes made elsewhere
+    pub on_disk: TrackerData,
+    pub disk_modified: Option<SystemTime>,
+    pub sync: Option<Reconciled>,  // Conflicts awaiting a decision
+    pub save_deferred: bool,  // The last save was held back until outside changes are merged
+    pub list_scope: ListScope,
+    pub trash_days_input: String,
     pub form: FormState,
     pub editing_index: Option<usize>,
     pub edit_form: FormState,
     pub error_message: Option<String>,
     pub last_saved: Option<String>,
     pub search_query: String,
-    pub filter_status: Option<JobStatus>,
+    pub search_matches: HashMap<String, JobMatch>,  // Job id to match for the current query, see refresh_search
+    pub sort_by_relevance: bool,
+    pub filter_status: StatusFilter,
+    pub filter_source: SourceFilter,
+    pub filter_location: LocationFilter,
+    pub filter_work_mode: WorkModeFilter,
+    pub sponsorship_only: bool,  // Only roles known to sponsor visas
+    pub group_by: GroupBy,
+    pub date_from: String,  // YYYY-MM-DD, ignored unless it parses
+    pub date_to: String,
+    pub stale_days: String,
     pub has_unsaved_changes: bool,  // New field to track actual data changes
-    pub sort_order: SortOrder,
-    pub sort_column: SortColumn,
+    pub sort_keys: Vec<SortKey>,  // Primary key first
+    pub modifiers: keyboard::Modifiers,
+    pub settings: Settings,
+    pub show_pipeline_editor: bool,
+    pub editing_rules_for: Option<usize>,  // Stage whose transition rules are expanded
+    pub stage_rename: Option<(usize, String)>,  // Stage name being typed, applied on submit
+    pub show_reason_stats: bool,
+    pub show_activity: bool,
+    pub show_timing: bool,
+    pub show_sources: bool,
+    pub new_source_input: String,
+    pub show_goals: bool,
+    pub new_goal: Goal,
+    pub new_goal_target: String,
+    pub networking_note: String,
+    pub expanded_notes: Option<usize>,  // Row whose notes panel is open
+    pub reading_description: Option<usize>,  // Row whose job description reader is open
+    pub keyword_resume: Option<String>,  // Resume compared against the open description
+    pub keyword_report: Option<(usize, KeywordReport)>,
+    pub analyzing_keywords: bool,
+    pub notes_markdown: Vec<markdown::Item>,
+    pub view_name_input: String,
+    pub duplicate_pairs: Vec<(usize, usize)>,  // From the last background scan
+    pub show_duplicates: bool,
+    pub merge: Option<MergeState>,
+    pub show_companies: bool,
+    pub new_company_input: String,
+    pub open_company: Option<CompanyView>,
+    pub company_page: CompanyPageState,
+    pub show_documents: bool,
+    pub new_document: NewDocument,
+    pub show_templates: bool,
+    pub editing_template: Option<usize>,
+    pub template_body: text_editor::Content,  // Body of the template being edited
+    pub template_preview: Option<TemplatePreview>,
+    pub calendar_exported: Option<String>,  // Path of the last .ics export
+    pub view_mode: ViewMode,
+    pub calendar_month: NaiveDate,  // First day of the month shown
+    pub day_filter: Option<NaiveDate>,  // Day picked on the calendar
 }
 
 impl JobTracker {
     pub fn new() -> Self {
         // Load jobs from storage, falling back to empty if error
-        let jobs = storage::load_jobs().unwrap_or_else(|err| {
+        let data = storage::load_data().unwrap_or_else(|err| {
             eprintln!("Error loading jobs: {}", err);
-            Vec::new()
+            TrackerData::default()
         });
 
-        Self {
-            jobs,
+        let mut settings = storage::load_settings().unwrap_or_else(|err| {
+            eprintln!("Error loading settings: {}", err);
+            Settings::default()
+        });
+        // A hand-edited retention period of zero would empty the trash on launch
+        settings.trash_days = settings.trash_days.clamp(1, data::MAX_TRASH_DAYS);
+
+        let mut state = Self {
+            on_disk: data.clone(),
+            disk_modified: storage::data_modified(),
+            jobs: data.jobs,
+            pipeline: data.pipeline,
+            companies: data.companies,
+            documents: data.documents,
+            networking: data.networking,
+            trash: data.trash,
+            trash_days_input: settings.trash_days.to_string(),
+            settings,
+            calendar_month: calendar::first_of_month(Local::now().date_naive()),
             has_unsaved_changes: false,
             ..Default::default()
+        };
+        state.purge_expired_trash();
+        state.refresh_search();
+        state
+    }
+
+    // Match every application against the search query. Runs when the query or the applications change
+    // rather than on each render, since descriptions make matching expensive
+    pub fn refresh_search(&mut self) {
+        let query = SearchQuery::new(&self.search_query);
+        self.search_matches = if query.is_empty() {
+            HashMap::new()
+        } else {
+            self.jobs
+                .iter()
+                .filter_map(|job| query.match_job(job).map(|m| (job.id.clone(), m)))
+                .collect()
+        };
+    }
+
+    // Everything that goes in the data file, as it is now
+    pub fn snapshot(&self) -> TrackerData {
+        TrackerData {
+            pipeline: self.pipeline.clone(),
+            companies: self.companies.clone(),
+            documents: self.documents.clone(),
+            networking: self.networking.clone(),
+            jobs: self.jobs.clone(),
+            trash: self.trash.clone(),
+        }
+    }
+
+    // Replace the data with a loaded or merged copy, keeping an open edit on the same application
+    pub fn apply_data(&mut self, data: TrackerData) {
+        let editing_id = self.editing_index.and_then(|index| self.jobs.get(index)).map(|job| job.id.clone());
+
+        self.jobs = data.jobs;
+        self.pipeline = data.pipeline;
+        self.companies = data.companies;
+        self.documents = data.documents;
+        self.networking = data.networking;
+        self.trash = data.trash;
+
+        self.editing_index = editing_id.and_then(|id| self.jobs.iter().position(|job| job.id == id));
+        if self.editing_index.is_none() {
+            self.edit_form = FormState::default();
+        }
+        // Row indices may have shifted
+        self.open_company = None;
+        self.expanded_notes = None;
+        self.reading_description = None;
+        self.keyword_report = None;
+        self.template_preview = None;
+        self.merge = None;
+        self.duplicate_pairs.clear();
+        self.refresh_search();
+    }
+
+    // Id of the application whose edit form holds changes not yet saved
+    pub fn pending_edit(&self) -> Option<&str> {
+        self.editing_index
+            .filter(|_| self.has_unsaved_changes)
+            .and_then(|index| self.jobs.get(index))
+            .map(|job| job.id.as_str())
+    }
+
+    // Drop trashed applications older than the retention period, saving if any went.
+    // Runs at startup and on a timer, never while the setting is being typed
+    pub fn purge_expired_trash(&mut self) {
+        let today = Local::now().date_naive();
+        let keep_days = self.settings.trash_days;
+        let before = self.trash.len();
+        self.trash.retain(|trashed| trashed.purge_on(keep_days).is_none_or(|on| on > today));
+        if self.trash.len() != before {
+            self.save_outside_edit();
+        }
+    }
+
+    // Open the notes panel for a row, or close it if it is already open
+    pub fn toggle_notes(&mut self, index: usize) {
+        if self.expanded_notes == Some(index) || index >= self.jobs.len() {
+            self.expanded_notes = None;
+            self.notes_markdown.clear();
+        } else {
+            self.expanded_notes = Some(index);
+            self.notes_markdown = markdown::parse(&self.jobs[index].notes).collect();
+        }
+    }
+
+    // Re-parse the open notes panel after its job changes
+    pub fn refresh_notes(&mut self) {
+        match self.expanded_notes {
+            Some(index) if index < self.jobs.len() => {
+                self.notes_markdown = markdown::parse(&self.jobs[index].notes).collect();
+            }
+            _ => {
+                self.expanded_notes = None;
+                self.notes_markdown.clear();
+            }
+        }
+    }
+
+    // Apply the stage name being typed, if any. Returns whether anything changed
+    pub fn commit_stage_rename(&mut self) -> Result<bool, String> {
+        let Some((index, name)) = self.stage_rename.take() else {
+            return Ok(false);
+        };
+        let unchanged = self.pipeline.stages.get(index).is_none_or(|stage| stage.name == name.trim());
+        if unchanged {
+            return Ok(false);
+        }
+        match self.rename_stage(index, name.trim()) {
+            Ok(()) => Ok(true),
+            Err(err) => {
+                // Keep the typed name so it can be corrected
+                self.stage_rename = Some((index, name));
+                Err(err)
+            }
+        }
+    }
+
+    // Rename a stage and everything that refers to it: applications, transition rules, open forms and filters
+    pub fn rename_stage(&mut self, index: usize, name: &str) -> Result<(), String> {
+        let Some(old_name) = self.pipeline.stages.get(index).map(|stage| stage.name.clone()) else {
+            return Ok(());
+        };
+        if name.is_empty() {
+            return Err("Stage name cannot be empty".to_string());
+        }
+        if self.pipeline.stages.iter().enumerate().any(|(i, stage)| i != index && stage.name.eq_ignore_ascii_case(name)) {
+            return Err(format!("A stage named \"{}\" already exists", name));
+        }
+
+        let rename = |value: &mut String| {
+            if *value == old_name {
+                *value = name.to_string();
+            }
+        };
+        // History and closure reasons too, since response and timing stats look stage names up in the pipeline
+        let trashed = self.trash.iter_mut().map(|trashed| &mut trashed.job);
+        for job in self.jobs.iter_mut().chain(trashed) {
+            rename(&mut job.status);
+            for change in &mut job.history {
+                change.from.iter_mut().for_each(rename);
+                rename(&mut change.to);
+            }
+            job.closure_reason.iter_mut().for_each(|reason| rename(&mut reason.stage_reached));
+        }
+        self.pipeline
+            .stages
+            .iter_mut()
+            .flat_map(|stage| stage.allowed_next.iter_mut().flatten())
+            .for_each(rename);
+        for form in [&mut self.form, &mut self.edit_form] {
+            form.status.iter_mut().chain(form.reason_stage.iter_mut()).for_each(rename);
+        }
+        let filters = std::iter::once(&mut self.filter_status)
+            .chain(self.settings.saved_views.iter_mut().map(|view| &mut view.filter_status));
+        for filter in filters {
+            if let StatusFilter::Only(stage) = filter {
+                rename(stage);
+            }
+        }
+        self.pipeline.stages[index].name = name.to_string();
+        Ok(())
+    }
+
+    // Stages still holding applications, including trashed ones, cannot be removed
+    pub fn remove_stage(&mut self, index: usize) -> Result<(), String> {
+        let Some(stage) = self.pipeline.stages.get(index) else {
+            return Ok(());
+        };
+        let in_use = self.jobs.iter().filter(|job| job.status == stage.name).count();
+        if in_use > 0 {
+            return Err(format!(
+                "Move the {} application(s) in \"{}\" to another stage first",
+                in_use, stage.name
+            ));
+        }
+        let in_trash = self.trash.iter().filter(|trashed| trashed.job.status == stage.name).count();
+        if in_trash > 0 {
+            return Err(format!(
+                "{} application(s) in the trash are in \"{}\"; restore and move them, or delete them for good, first",
+                in_trash, stage.name
+            ));
+        }
+
+        let removed = self.pipeline.stages.remove(index);
+        for allowed in self.pipeline.stages.iter_mut().filter_map(|stage| stage.allowed_next.as_mut()) {
+            allowed.retain(|name| *name != removed.name);
+        }
+        Ok(())
+    }
+
+    // Existing applications that look like the one being entered in the add form
+    pub fn form_duplicates(&self) -> Vec<(usize, DuplicateReason)> {
+        if self.form.company.trim().is_empty() && self.form.url.trim().is_empty() {
+            return Vec::new();
+        }
+        let candidate = JobApplication {
+            company: self.form.company.clone(),
+            position: self.form.position.clone(),
+            url: Some(self.form.url.clone()).filter(|url| !url.trim().is_empty()),
+            ..Default::default()
+        };
+        duplicates::matches_for(&candidate, &self.jobs)
+    }
+
+    // Scan results can go stale after edits, so re-check each pair before showing it
+    pub fn current_duplicates(&self) -> Vec<(usize, usize, DuplicateReason)> {
+        self.duplicate_pairs
+            .iter()
+            .filter_map(|&(a, b)| {
+                let reason = duplicates::duplicate_reason(self.jobs.get(a)?, self.jobs.get(b)?)?;
+                Some((a, b, reason))
+            })
+            .collect()
+    }
+
+    // Show a company's page. Viewing changes nothing; an unregistered company is only added from its page
+    pub fn open_company(&mut self, name: &str) {
+        let (view, rename) = match company::find_company(&self.companies, name) {
+            Some(index) => (CompanyView::Registered(index), self.companies[index].name.clone()),
+            None if !name.trim().is_empty() => (CompanyView::Unregistered(name.trim().to_string()), name.trim().to_string()),
+            None => return,
+        };
+
+        self.open_company = Some(view);
+        self.company_page = CompanyPageState {
+            rename,
+            ..Default::default()
+        };
+    }
+
+    // Add a company to the registry, filing matching applications under it, and show its page
+    pub fn register_company(&mut self, name: &str) {
+        if name.trim().is_empty() {
+            return;
+        }
+        let index = company::find_company(&self.companies, name).unwrap_or_else(|| {
+            self.companies.push(Company::new(name));
+            self.companies.len() - 1
+        });
+        company::normalize_job_companies(&self.companies, &mut self.jobs);
+        self.has_unsaved_changes = true;
+        self.open_company = Some(CompanyView::Registered(index));
+    }
+
+    // Rename a registered company and every application filed under it
+    pub fn rename_company(&mut self, index: usize, name: &str) -> Result<(), String> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err("Company name cannot be empty".to_string());
+        }
+        let Some(old_name) = self.companies.get(index).map(|c| c.name.clone()) else {
+            return Ok(());
+        };
+        if let Some(other) = company::find_company(&self.companies, name).filter(|&i| i != index) {
+            return Err(format!("\"{}\" is already registered as \"{}\"", name, self.companies[other].name));
+        }
+
+        // The old spelling keeps matching so older imports still land here
+        let entry = &mut self.companies[index];
+        entry.aliases.retain(|alias| alias != name);
+        if !entry.aliases.contains(&old_name) {
+            entry.aliases.push(old_name.clone());
+        }
+        entry.name = name.to_string();
+        for job in self.jobs.iter_mut().filter(|job| job.company == old_name) {
+            job.company = name.to_string();
+        }
+        Ok(())
+    }
+
+    // Applications filed under a registered company
+    pub fn company_jobs(&self, index: usize) -> Vec<(usize, &JobApplication)> {
+        self.companies
+            .get(index)
+            .map(|entry| self.jobs_matching(entry))
+            .unwrap_or_default()
+    }
+
+    // Applications whose company matches an entry, registered or not
+    pub fn jobs_matching(&self, entry: &Company) -> Vec<(usize, &JobApplication)> {
+        self.jobs
+            .iter()
+            .enumerate()
+            .filter(|(_, job)| entry.matches(&job.company))
+            .collect()
+    }
+
+    pub fn document(&self, id: &str) -> Option<&Document> {
+        self.documents.iter().find(|doc| doc.id == id)
+    }
+
+    // Library documents of one kind, for the form's pickers
+    pub fn documents_of(&self, kind: DocumentKind) -> Vec<Document> {
+        self.documents.iter().filter(|doc| doc.kind == kind).cloned().collect()
+    }
+
+    // The edit form while a job is being edited, otherwise the add form
+    pub fn active_form_mut(&mut self) -> &mut FormState {
+        if self.editing_index.is_some() { &mut self.edit_form } else { &mut self.form }
+    }
+
+    // Contacts recorded for a company, if it is in the registry
+    pub fn company_contacts(&self, name: &str) -> &[Contact] {
+        company::find_company(&self.companies, name)
+            .map(|index| self.companies[index].contacts.as_slice())
+            .unwrap_or_default()
+    }
+
+    // The application, template and contact picked in the open preview
+    pub fn preview_parts(&self) -> Option<(&JobApplication, &Template, Option<&Contact>)> {
+        let preview = self.template_preview.as_ref()?;
+        let job = self.jobs.get(preview.job)?;
+        let template = self.settings.templates.get(preview.template?)?;
+        let contact = preview.contact.and_then(|i| self.company_contacts(&job.company).get(i));
+        Some((job, template, contact))
+    }
+
+    pub fn rendered_preview(&self) -> Option<Rendered> {
+        self.preview_parts()
+            .map(|(job, template, contact)| templates::render(template, job, contact))
+    }
+
+    pub fn write_live_calendar(&mut self) {
+        if let Err(err) = icalendar::write_live(&self.jobs) {
+            self.error_message = Some(format!("Error writing calendar: {}", err));
+            eprintln!("Error writing calendar: {}", err);
+        }
+    }
+
+    pub fn save_settings(&mut self) {
+        if let Err(err) = storage::save_settings(&self.settings) {
+            self.error_message = Some(format!("Error saving settings: {}", err));
+            eprintln!("Error saving settings: {}", err);
+        }
+    }
+
+    // Built-in views first, followed by the user's own
+    pub fn all_views(&self) -> Vec<SavedView> {
+        let mut views = SavedView::builtins();
+        views.extend(self.settings.saved_views.iter().cloned());
+        views
+    }
+
+    // Snapshot of the filter and sort settings currently on screen
+    pub fn current_view(&self, name: &str) -> SavedView {
+        SavedView {
+            name: name.to_string(),
+            filter_status: self.filter_status.clone(),
+            filter_source: self.filter_source.clone(),
+            filter_location: self.filter_location.clone(),
+            filter_work_mode: self.filter_work_mode,
+            sponsorship_only: self.sponsorship_only,
+            group_by: self.group_by,
+            search_query: self.search_query.clone(),
+            sort_by_relevance: self.sort_by_relevance,
+            sort_keys: self.sort_keys.clone(),
+            date_from: self.date_from.clone(),
+            date_to: self.date_to.clone(),
+            stale_days: self.stale_days.clone(),
         }
     }
 
+    pub fn apply_view(&mut self, view: &SavedView) {
+        self.filter_status = view.filter_status.clone();
+        self.filter_source = view.filter_source.clone();
+        self.filter_location = view.filter_location.clone();
+        self.filter_work_mode = view.filter_work_mode;
+        self.sponsorship_only = view.sponsorship_only;
+        self.group_by = view.group_by;
+        self.search_query = view.search_query.clone();
+        self.sort_by_relevance = view.sort_by_relevance;
+        self.sort_keys = view.sort_keys.clone();
+        self.date_from = view.date_from.clone();
+        self.date_to = view.date_to.clone();
+        self.stale_days = view.stale_days.clone();
+        self.refresh_search();
+    }
+
+    // A view is active when the on-screen settings exactly match it
+    pub fn is_view_active(&self, view: &SavedView) -> bool {
+        self.current_view(&view.name) == *view
+    }
+
+    // Save a change made outside the edit form, which still has its own unsaved edits if it had any
+    pub fn save_outside_edit(&mut self) {
+        let pending_edit = self.editing_index.is_some() && self.has_unsaved_changes;
+        self.save();
+        self.has_unsaved_changes |= pending_edit;
+    }
+
     pub fn save(&mut self) {
+        self.refresh_search();
+
+        // Writing now would overwrite changes made outside the app; they are merged in on the next check,
+        // or once the open conflicts are resolved
+        if self.sync.is_some() || storage::data_modified() != self.disk_modified {
+            self.has_unsaved_changes = true;
+            self.save_deferred = true;
+            return;
+        }
+
         // Update last_saved timestamp
         let now = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
 
-        match storage::save_jobs(&self.jobs) {
+        match storage::save_data(&self.pipeline, &self.companies, &self.documents, &self.networking, &self.jobs, &self.trash) {
             Ok(_) => {
                 self.last_saved = Some(now);
                 self.error_message = None;
                 self.has_unsaved_changes = false;  // Reset flag after saving
+                self.save_deferred = false;
+                self.on_disk = self.snapshot();
+                self.disk_modified = storage::data_modified();
+                if self.settings.live_calendar {
+                    self.write_live_calendar();
+                }
             }
             Err(err) => {
                 self.error_message = Some(format!("Error saving: {}", err));
@@ -126,49 +909,205 @@ impl JobTracker {
         }
     }
 
-    // Filter jobs based on current search query and status filter
-    pub fn filtered_jobs(&self) -> Vec<(usize, &JobApplication)> {
+    // Filter jobs on the current filters, along with their search relevance (zero when not searching)
+    pub fn filtered_jobs(&self) -> Vec<(usize, &JobApplication, u32)> {
+        let date_from = parse_filter_date(&self.date_from);
+        let date_to = parse_filter_date(&self.date_to);
+        // A cutoff too far back to represent leaves nothing old enough to match
+        let stale_days = self.stale_days.trim();
+        let stale_before = (!stale_days.is_empty()).then(|| {
+            stale_days.parse::<i64>().ok()
+                .and_then(TimeDelta::try_days)
+                .and_then(|age| Local::now().date_naive().checked_sub_signed(age))
+                .unwrap_or(NaiveDate::MIN)
+        });
+        let searching = !self.search_query.trim().is_empty();
+
         self.jobs.iter().enumerate()
-            .filter(|(_, job)| {
-                // Status filter and search logic remains the same
-                let status_match = match self.filter_status {
-                    None | Some(JobStatus::All) => true,
-                    Some(status) => job.status == status,
-                };
-
-                let search_match = self.search_query.is_empty() ||
-                    job.company.to_lowercase().contains(&self.search_query.to_lowercase()) ||
-                    job.position.to_lowercase().contains(&self.search_query.to_lowercase()) ||
-                    job.notes.to_lowercase().contains(&self.search_query.to_lowercase());
-
-                status_match && search_match
+            .filter_map(|(index, job)| {
+                let status_match = self.filter_status.matches(&job.status, &self.pipeline)
+                    && self.filter_source.matches(&job.source);
+
+                let location_match = self.filter_location.matches(&job.location)
+                    && self.filter_work_mode.matches(job.work_mode)
+                    && (!self.sponsorship_only || job.visa_sponsorship == Some(Sponsorship::Offered));
+
+                // Jobs without a parseable date never match an active date filter
+                let applied_on = job.applied_on();
+                let date_match = date_from.is_none_or(|from| applied_on.is_some_and(|d| d >= from)) &&
+                    date_to.is_none_or(|to| applied_on.is_some_and(|d| d <= to));
+
+                let stale_match = stale_before
+                    .is_none_or(|cutoff| job.last_activity().is_some_and(|d| d < cutoff));
+
+                let day_match = self.day_filter.is_none_or(|day| calendar::has_date(job, day));
+
+                // Archived applications only show in the archive
+                if job.archived != (self.list_scope == ListScope::Archive) {
+                    return None;
+                }
+
+                if !(status_match && location_match && date_match && stale_match && day_match) {
+                    return None;
+                }
+
+                if searching {
+                    self.search_matches.get(&job.id).map(|m| (index, job, m.score))
+                } else {
+                    Some((index, job, 0))
+                }
             })
             .collect()
     }
 
+    // Matched spans for highlighting a row, if a search is active
+    pub fn search_highlights(&self, job: &JobApplication) -> Option<&Highlights> {
+        self.search_matches.get(&job.id).map(|m| &m.highlights)
+    }
+
     // Get filtered and sorted jobs
     pub fn sorted_jobs(&self) -> Vec<(usize, &JobApplication)> {
         let mut jobs = self.filtered_jobs();
+        let by_relevance = self.sort_by_relevance && !self.search_query.trim().is_empty();
+
+        // Best matches first when requested, then each sort key in turn breaking ties
+        if by_relevance || !self.sort_keys.is_empty() {
+            let now = Local::now().naive_local();
+            jobs.sort_by(|(_, a, score_a), (_, b, score_b)| {
+                let relevance = if by_relevance { score_b.cmp(score_a) } else { Ordering::Equal };
+                self.sort_keys
+                    .iter()
+                    .fold(relevance, |ord, key| ord.then_with(|| key.compare(a, b, &self.pipeline, now)))
+            });
+        }
+
+        // Pinned applications come first; the sort is stable so each part keeps its order
+        jobs.sort_by_key(|(_, job, _)| !job.pinned);
+
+        jobs.into_iter().map(|(index, job, _)| (index, job)).collect()
+    }
+
+    // Upcoming deadlines of active applications due within DEADLINE_URGENT_HOURS, soonest first
+    pub fn urgent_deadlines(&self) -> Vec<(&JobApplication, &Deadline, NaiveDateTime)> {
+        let now = Local::now().naive_local();
+        let cutoff = now + Duration::hours(data::DEADLINE_URGENT_HOURS);
+        let mut urgent: Vec<_> = self
+            .jobs
+            .iter()
+            .filter(|job| self.pipeline.is_active(&job.status))
+            .flat_map(|job| job.deadlines.iter().map(move |deadline| (job, deadline)))
+            .filter_map(|(job, deadline)| {
+                let due = deadline.due()?;
+                (due >= now && due <= cutoff).then_some((job, deadline, due))
+            })
+            .collect();
+        urgent.sort_by_key(|(_, _, due)| *due);
+        urgent
+    }
 
-        // Apply sorting if active
-        if self.sort_order != SortOrder::None {
-            match self.sort_column {
-                SortColumn::Company => {
-                    jobs.sort_by(|(_, a), (_, b)| {
-                        let cmp = a.company.to_lowercase().cmp(&b.company.to_lowercase());
-                        if self.sort_order == SortOrder::Ascending { cmp } else { cmp.reverse() }
-                    });
-                },
-                SortColumn::DateApplied => {
-                    jobs.sort_by(|(_, a), (_, b)| {
-                        let cmp = a.date_applied.cmp(&b.date_applied);
-                        if self.sort_order == SortOrder::Ascending { cmp } else { cmp.reverse() }
-                    });
-                },
-                SortColumn::None => {},
+    // Sorted jobs split into sections by the chosen field, named sections alphabetically and unrecorded last
+    pub fn grouped_jobs(&self) -> Vec<(String, Vec<(usize, &JobApplication)>)> {
+        let mut groups: Vec<(Option<String>, Vec<_>)> = Vec::new();
+        for (index, job) in self.sorted_jobs() {
+            let key = self.group_by.key(job);
+            let existing = groups.iter_mut().find(|(k, _)| match (k, &key) {
+                (Some(a), Some(b)) => a.eq_ignore_ascii_case(b),
+                (None, None) => true,
+                _ => false,
+            });
+            match existing {
+                Some((_, jobs)) => jobs.push((index, job)),
+                None => groups.push((key, vec![(index, job)])),
             }
         }
 
-        jobs
+        groups.sort_by_key(|(key, _)| (key.is_none(), key.as_ref().map(|k| k.to_lowercase())));
+        groups
+            .into_iter()
+            .map(|(key, jobs)| (key.unwrap_or_else(|| self.group_by.unspecified_label().to_string()), jobs))
+            .collect()
+    }
+
+    // Plain click sorts by a single column, shift-click adds further keys
+    pub fn toggle_sort(&mut self, column: SortColumn, additive: bool) {
+        let position = self.sort_keys.iter().position(|key| key.column == column);
+
+        let is_only_key = position == Some(0) && self.sort_keys.len() == 1;
+        if !additive && !is_only_key {
+            self.sort_keys = vec![SortKey::new(column, SortOrder::Ascending)];
+            return;
+        }
+
+        // Cycle the existing key: ascending -> descending -> removed
+        match position {
+            Some(i) => match self.sort_keys[i].order {
+                SortOrder::Ascending => self.sort_keys[i].order = SortOrder::Descending,
+                SortOrder::Descending => {
+                    self.sort_keys.remove(i);
+                }
+            },
+            None => self.sort_keys.push(SortKey::new(column, SortOrder::Ascending)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::StatusChange;
+
+    fn applied_job() -> JobApplication {
+        JobApplication {
+            id: "a".to_string(),
+            status: "Applied".to_string(),
+            history: vec![StatusChange {
+                from: None,
+                to: "Applied".to_string(),
+                at: "2026-01-05 09:00:00".to_string(),
+                note: String::new(),
+                forced: false,
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn renaming_the_first_stage_keeps_response_stats() {
+        let mut state = JobTracker {
+            jobs: vec![applied_job()],
+            trash: vec![TrashedJob { job: applied_job(), deleted_at: "2026-01-06 09:00:00".to_string() }],
+            ..Default::default()
+        };
+        assert!(!state.jobs[0].got_response(&state.pipeline));
+
+        state.rename_stage(0, "Submitted").unwrap();
+        let job = &state.jobs[0];
+        assert!(!job.got_response(&state.pipeline));
+        assert_eq!(job.history[0].to, "Submitted");
+        assert_eq!(state.trash[0].job.status, "Submitted");
+    }
+
+    #[test]
+    fn stage_used_in_the_trash_cannot_be_removed() {
+        let mut state = JobTracker {
+            trash: vec![TrashedJob { job: applied_job(), deleted_at: "2026-01-06 09:00:00".to_string() }],
+            ..Default::default()
+        };
+        assert!(state.remove_stage(0).is_err());
+        assert_eq!(state.pipeline.stages[0].name, "Applied");
+    }
+
+    #[test]
+    fn huge_stale_days_match_nothing() {
+        let mut job = applied_job();
+        job.date_applied = "2020-01-05".to_string();
+        let mut state = JobTracker { jobs: vec![job], ..Default::default() };
+
+        state.stale_days = "30".to_string();
+        assert_eq!(state.filtered_jobs().len(), 1);
+        for days in ["999999999999", "99999999999999999999999"] {
+            state.stale_days = days.to_string();
+            assert!(state.filtered_jobs().is_empty(), "{days} days");
+        }
     }
 }
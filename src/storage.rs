@@ -1,10 +1,17 @@
-use crate::data::JobApplication;
+use crate::company::{self, Company};
+use crate::data::{new_id, JobApplication, Settings, TrackerData, TrashedJob};
+use crate::documents::Document;
+use crate::goals::NetworkingEntry;
+use crate::pipeline::Pipeline;
+use serde::{Deserialize, Serialize};
 use std::fs::{self, File};
-use std::io::{BufReader, BufWriter};
+use std::io::{BufReader, BufWriter, Write};
 use std::path::Path;
+use std::time::SystemTime;
 use thiserror::Error;
 
 const DATA_FILE: &str = "job_applications.json";
+const SETTINGS_FILE: &str = "job_tracker_settings.json";
 
 #[derive(Error, Debug)]
 pub enum StorageError {
@@ -29,36 +36,120 @@ pub enum StorageError {
 
 pub type Result<T> = std::result::Result<T, StorageError>;
 
-/// Loads job applications from the JSON file
-pub fn load_jobs() -> Result<Vec<JobApplication>> {
+// Data files written before pipelines were configurable hold a bare array of jobs
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum DataFile {
+    Current(TrackerData),
+    Legacy(Vec<JobApplication>),
+}
+
+#[derive(Serialize)]
+struct DataFileRef<'a> {
+    pipeline: &'a Pipeline,
+    companies: &'a [Company],
+    documents: &'a [Document],
+    networking: &'a [NetworkingEntry],
+    jobs: &'a [JobApplication],
+    trash: &'a [TrashedJob],
+}
+
+/// Loads the pipeline, company registry, document library, networking log, job applications and trash from the JSON file
+pub fn load_data() -> Result<TrackerData> {
     let path = Path::new(DATA_FILE);
 
-    // If file doesn't exist yet, return empty vector
+    // If file doesn't exist yet, start with the default pipeline and no jobs
     if !path.exists() {
-        return Ok(Vec::new());
+        return Ok(TrackerData::default());
     }
 
     let file = File::open(path)
         .map_err(|_| StorageError::FileOpen(DATA_FILE.to_string()))?;
     let reader = BufReader::new(file);
 
-    serde_json::from_reader(reader)
-        .map_err(|e| {
-            if e.is_syntax() || e.is_data() {
-                StorageError::ParseError
-            } else {
-                StorageError::Json(e)
-            }
-        })
+    let mut data = match serde_json::from_reader(reader) {
+        Ok(DataFile::Current(data)) => data,
+        Ok(DataFile::Legacy(jobs)) => TrackerData {
+            pipeline: Pipeline::default(),
+            companies: Vec::new(),
+            documents: Vec::new(),
+            networking: Vec::new(),
+            jobs,
+            trash: Vec::new(),
+        },
+        Err(e) if e.is_syntax() || e.is_data() => return Err(StorageError::ParseError),
+        Err(e) => return Err(StorageError::Json(e)),
+    };
+
+    data.pipeline.adopt_statuses(&data.jobs);
+    // Trashed applications keep their stage so they can be restored into it
+    let trashed: Vec<JobApplication> = data.trash.iter().map(|trashed| trashed.job.clone()).collect();
+    data.pipeline.adopt_statuses(&trashed);
+    // Spellings that differ from a registered company's name are filed under it
+    company::normalize_job_companies(&data.companies, &mut data.jobs);
+
+    // Older files have no ids; write new ones back at once so they stay stable between runs
+    let mut assigned = false;
+    for job in data.jobs.iter_mut().filter(|job| job.id.is_empty()) {
+        job.id = new_id();
+        assigned = true;
+    }
+    if assigned {
+        save_data(&data.pipeline, &data.companies, &data.documents, &data.networking, &data.jobs, &data.trash)?;
+    }
+    Ok(data)
 }
 
-/// Saves job applications to the JSON file
-pub fn save_jobs(jobs: &[JobApplication]) -> Result<()> {
-    let file = File::create(DATA_FILE)
+/// Saves the pipeline, company registry, document library, networking log, job applications and trash to the JSON file
+pub fn save_data(
+    pipeline: &Pipeline,
+    companies: &[Company],
+    documents: &[Document],
+    networking: &[NetworkingEntry],
+    jobs: &[JobApplication],
+    trash: &[TrashedJob],
+) -> Result<()> {
+    // Write beside the file and rename over it, so other readers never see a half-written file
+    let temp_file = format!("{}.tmp", DATA_FILE);
+    let file = File::create(&temp_file)
+        .map_err(|_| StorageError::FileCreate(temp_file.clone()))?;
+    let mut writer = BufWriter::new(file);
+
+    serde_json::to_writer_pretty(&mut writer, &DataFileRef { pipeline, companies, documents, networking, jobs, trash })?;
+    writer.flush().map_err(|_| StorageError::FileCreate(temp_file.clone()))?;
+    drop(writer);
+    fs::rename(&temp_file, DATA_FILE)
         .map_err(|_| StorageError::FileCreate(DATA_FILE.to_string()))?;
+    Ok(())
+}
+
+/// When the data file was last written, by this app or anything else
+pub fn data_modified() -> Option<SystemTime> {
+    fs::metadata(DATA_FILE).and_then(|meta| meta.modified()).ok()
+}
+
+/// Loads user settings such as saved views, falling back to defaults
+pub fn load_settings() -> Result<Settings> {
+    let path = Path::new(SETTINGS_FILE);
+
+    if !path.exists() {
+        return Ok(Settings::default());
+    }
+
+    let file = File::open(path)
+        .map_err(|_| StorageError::FileOpen(SETTINGS_FILE.to_string()))?;
+    let reader = BufReader::new(file);
+
+    serde_json::from_reader(reader).map_err(|_| StorageError::ParseError)
+}
+
+/// Saves user settings to their own JSON file
+pub fn save_settings(settings: &Settings) -> Result<()> {
+    let file = File::create(SETTINGS_FILE)
+        .map_err(|_| StorageError::FileCreate(SETTINGS_FILE.to_string()))?;
     let writer = BufWriter::new(file);
 
-    serde_json::to_writer_pretty(writer, jobs)?;
+    serde_json::to_writer_pretty(writer, settings)?;
     Ok(())
 }
 
@@ -0,0 +1,446 @@
+use crate::data::{JobApplication, TrackerData};
+use crate::pipeline::Pipeline;
+
+// How often the data file is checked for changes made outside the app
+pub const POLL_SECONDS: u64 = 2;
+
+/// An application changed both here and in the file since they last matched
+#[derive(Debug, Clone)]
+pub struct Conflict {
+    pub id: String,
+    pub mine: Option<JobApplication>,    // None when deleted here
+    pub theirs: Option<JobApplication>,  // None when deleted from the file
+    pub keep_mine: bool,
+}
+
+impl Conflict {
+    // Company and position for the dialog, from whichever side still has the application
+    pub fn title(&self) -> String {
+        self.mine
+            .as_ref()
+            .or(self.theirs.as_ref())
+            .map(|job| format!("{} - {}", job.company, job.position))
+            .unwrap_or_default()
+    }
+
+    // Names of the fields the two versions disagree on
+    pub fn differences(&self) -> Vec<&'static str> {
+        let (Some(mine), Some(theirs)) = (&self.mine, &self.theirs) else {
+            return Vec::new();
+        };
+        [
+            ("Company", mine.company != theirs.company),
+            ("Position", mine.position != theirs.position),
+            ("Date applied", mine.date_applied != theirs.date_applied),
+            ("Status", mine.status != theirs.status || mine.history != theirs.history),
+            ("Notes", mine.notes != theirs.notes),
+            ("URL", mine.url != theirs.url || mine.other_urls != theirs.other_urls),
+            ("Description", mine.description != theirs.description),
+            ("Documents", mine.resume != theirs.resume || mine.cover_letter != theirs.cover_letter),
+            ("Source", mine.source != theirs.source),
+            ("Location", mine.location != theirs.location || mine.work_mode != theirs.work_mode),
+            ("Sponsorship", mine.visa_sponsorship != theirs.visa_sponsorship),
+            ("Schedule", mine.events != theirs.events),
+            ("Deadlines", mine.deadlines != theirs.deadlines),
+            ("Priority", mine.priority != theirs.priority || mine.pinned != theirs.pinned),
+            ("Closure reason", mine.closure_reason != theirs.closure_reason),
+            ("Archived", mine.archived != theirs.archived),
+        ]
+        .into_iter()
+        .filter_map(|(field, differs)| differs.then_some(field))
+        .collect()
+    }
+}
+
+/// Both sides combined, holding my version of each conflicting application until resolved
+#[derive(Debug, Clone)]
+pub struct Reconciled {
+    pub data: TrackerData,
+    pub conflicts: Vec<Conflict>,
+    // Stages, companies and other entries changed on both sides, where my version was kept
+    pub kept_mine: Vec<String>,
+}
+
+fn find<'a>(jobs: &'a [JobApplication], id: &str) -> Option<&'a JobApplication> {
+    jobs.iter().find(|job| job.id == id)
+}
+
+fn find_by<'a, T, K: PartialEq>(items: &'a [T], key: &impl Fn(&T) -> K, wanted: &K) -> Option<&'a T> {
+    items.iter().find(|item| key(item) == *wanted)
+}
+
+// Three-way merge of a list other than the applications, entry by entry on `key`: an entry added, changed
+// or removed on one side follows that side. One changed on both sides keeps my version and is named in `kept`
+fn merge_items<T: Clone + PartialEq, K: PartialEq>(
+    base: &[T],
+    mine: &[T],
+    theirs: &[T],
+    key: impl Fn(&T) -> K,
+    describe: impl Fn(&T) -> String,
+    kept: &mut Vec<String>,
+) -> Vec<T> {
+    // Whole-list shortcuts also carry over reordering, e.g. of pipeline stages
+    if mine == base {
+        return theirs.to_vec();
+    }
+    if theirs == base || theirs == mine {
+        return mine.to_vec();
+    }
+
+    let mut merged = Vec::with_capacity(mine.len());
+    for item in mine {
+        let id = key(item);
+        let before = find_by(base, &key, &id);
+        match find_by(theirs, &key, &id) {
+            // Unchanged there, or changed the same way
+            after if after == before || after == Some(item) => merged.push(item.clone()),
+            // Changed or removed there only
+            Some(after) if before == Some(item) => merged.push(after.clone()),
+            None if before == Some(item) => {}
+            _ => {
+                kept.push(describe(item));
+                merged.push(item.clone());
+            }
+        }
+    }
+
+    // Entries only the file has: added there, or removed here
+    for item in theirs {
+        let id = key(item);
+        if find_by(mine, &key, &id).is_some() {
+            continue;
+        }
+        match find_by(base, &key, &id) {
+            None => merged.push(item.clone()),
+            Some(before) if before == item => {}
+            // Removed here but changed there; the removal stands
+            Some(_) => kept.push(describe(item)),
+        }
+    }
+    merged
+}
+
+/// Three-way merge of my data and the file's against the version both started from.
+/// `pending` is an application with edits not yet saved, which counts as changed here.
+pub fn reconcile(base: &TrackerData, mine: &TrackerData, theirs: TrackerData, pending: Option<&str>) -> Reconciled {
+    let mut jobs = Vec::with_capacity(mine.jobs.len());
+    let mut conflicts = Vec::new();
+
+    for job in &mine.jobs {
+        let before = find(&base.jobs, &job.id);
+        let changed_here = before != Some(job) || pending == Some(job.id.as_str());
+        match find(&theirs.jobs, &job.id) {
+            // Unchanged there, or changed the same way
+            after if after == before || after == Some(job) => jobs.push(job.clone()),
+            // Changed or deleted there only
+            Some(after) if !changed_here => jobs.push(after.clone()),
+            None if !changed_here => {}
+            after => {
+                conflicts.push(Conflict {
+                    id: job.id.clone(),
+                    mine: Some(job.clone()),
+                    theirs: after.cloned(),
+                    keep_mine: true,
+                });
+                jobs.push(job.clone());
+            }
+        }
+    }
+
+    // Applications only the file has: added there, or deleted here
+    for job in &theirs.jobs {
+        if find(&mine.jobs, &job.id).is_some() {
+            continue;
+        }
+        match find(&base.jobs, &job.id) {
+            None => jobs.push(job.clone()),
+            Some(before) if before == job => {}
+            Some(_) => conflicts.push(Conflict {
+                id: job.id.clone(),
+                mine: None,
+                theirs: Some(job.clone()),
+                keep_mine: true,
+            }),
+        }
+    }
+
+    let mut kept_mine = Vec::new();
+    let stages = merge_items(
+        &base.pipeline.stages,
+        &mine.pipeline.stages,
+        &theirs.pipeline.stages,
+        |stage| stage.name.clone(),
+        |stage| format!("stage \"{}\"", stage.name),
+        &mut kept_mine,
+    );
+    let companies = merge_items(
+        &base.companies,
+        &mine.companies,
+        &theirs.companies,
+        |company| company.name.clone(),
+        |company| format!("company \"{}\"", company.name),
+        &mut kept_mine,
+    );
+    let documents = merge_items(
+        &base.documents,
+        &mine.documents,
+        &theirs.documents,
+        |doc| doc.id.clone(),
+        |doc| format!("document \"{}\"", doc.name),
+        &mut kept_mine,
+    );
+    // Log entries are never edited, only added or removed, so each is its own key
+    let networking = merge_items(
+        &base.networking,
+        &mine.networking,
+        &theirs.networking,
+        |entry| entry.clone(),
+        |entry| format!("networking entry on {}", entry.date),
+        &mut kept_mine,
+    );
+    let trash = merge_items(
+        &base.trash,
+        &mine.trash,
+        &theirs.trash,
+        |trashed| trashed.job.id.clone(),
+        |trashed| format!("trashed application \"{} - {}\"", trashed.job.company, trashed.job.position),
+        &mut kept_mine,
+    );
+
+    let mut data = TrackerData {
+        pipeline: Pipeline { stages },
+        companies,
+        documents,
+        networking,
+        trash,
+        jobs,
+    };
+    data.pipeline.adopt_statuses(&data.jobs);
+
+    Reconciled { data, conflicts, kept_mine }
+}
+
+/// Apply the choice made for each conflict
+pub fn resolve(reconciled: Reconciled) -> TrackerData {
+    let mut data = reconciled.data;
+    for conflict in reconciled.conflicts.into_iter().filter(|conflict| !conflict.keep_mine) {
+        let position = data.jobs.iter().position(|job| job.id == conflict.id);
+        match (position, conflict.theirs) {
+            (Some(index), Some(theirs)) => data.jobs[index] = theirs,
+            (Some(index), None) => {
+                data.jobs.remove(index);
+            }
+            (None, Some(theirs)) => {
+                // Deleted here, so it may be sitting in the trash
+                data.trash.retain(|trashed| trashed.job.id != conflict.id);
+                data.jobs.push(theirs);
+            }
+            (None, None) => {}
+        }
+    }
+    data.pipeline.adopt_statuses(&data.jobs);
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::company::Company;
+    use crate::data::TrashedJob;
+
+    fn job(id: &str, status: &str) -> JobApplication {
+        JobApplication {
+            id: id.to_string(),
+            company: format!("Company {}", id),
+            position: "Engineer".to_string(),
+            status: status.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn data(jobs: Vec<JobApplication>) -> TrackerData {
+        TrackerData { jobs, ..Default::default() }
+    }
+
+    fn statuses(data: &TrackerData) -> Vec<(&str, &str)> {
+        data.jobs.iter().map(|job| (job.id.as_str(), job.status.as_str())).collect()
+    }
+
+    #[test]
+    fn edit_on_one_side_only_is_taken() {
+        let base = data(vec![job("a", "Applied"), job("b", "Applied")]);
+        let mine = data(vec![job("a", "Interview"), job("b", "Applied")]);
+        let theirs = data(vec![job("a", "Applied"), job("b", "Rejected")]);
+
+        let reconciled = reconcile(&base, &mine, theirs, None);
+        assert!(reconciled.conflicts.is_empty());
+        assert_eq!(statuses(&reconciled.data), vec![("a", "Interview"), ("b", "Rejected")]);
+    }
+
+    #[test]
+    fn same_edit_on_both_sides_is_not_a_conflict() {
+        let base = data(vec![job("a", "Applied")]);
+        let mine = data(vec![job("a", "Offer")]);
+
+        let reconciled = reconcile(&base, &mine, mine.clone(), None);
+        assert!(reconciled.conflicts.is_empty());
+        assert_eq!(statuses(&reconciled.data), vec![("a", "Offer")]);
+    }
+
+    #[test]
+    fn edit_on_both_sides_is_a_conflict_holding_mine() {
+        let base = data(vec![job("a", "Applied")]);
+        let mine = data(vec![job("a", "Interview")]);
+        let theirs = data(vec![job("a", "Rejected")]);
+
+        let reconciled = reconcile(&base, &mine, theirs, None);
+        assert_eq!(reconciled.conflicts.len(), 1);
+        let conflict = &reconciled.conflicts[0];
+        assert_eq!(conflict.id, "a");
+        assert!(conflict.keep_mine);
+        assert_eq!(conflict.differences(), vec!["Status"]);
+        assert_eq!(statuses(&reconciled.data), vec![("a", "Interview")]);
+    }
+
+    #[test]
+    fn unsaved_edit_counts_as_changed_here() {
+        let base = data(vec![job("a", "Applied")]);
+        let theirs = data(vec![job("a", "Rejected")]);
+
+        let reconciled = reconcile(&base, &base.clone(), theirs, Some("a"));
+        assert_eq!(reconciled.conflicts.len(), 1);
+    }
+
+    #[test]
+    fn jobs_added_on_either_side_are_kept() {
+        let base = data(vec![job("a", "Applied")]);
+        let mine = data(vec![job("a", "Applied"), job("mine", "Applied")]);
+        let theirs = data(vec![job("a", "Applied"), job("theirs", "Applied")]);
+
+        let reconciled = reconcile(&base, &mine, theirs, None);
+        assert!(reconciled.conflicts.is_empty());
+        assert_eq!(
+            statuses(&reconciled.data),
+            vec![("a", "Applied"), ("mine", "Applied"), ("theirs", "Applied")]
+        );
+    }
+
+    #[test]
+    fn deletion_on_one_side_only_is_taken() {
+        let base = data(vec![job("a", "Applied"), job("b", "Applied")]);
+        let mine = data(vec![job("b", "Applied")]);
+        let theirs = data(vec![job("a", "Applied")]);
+
+        let reconciled = reconcile(&base, &mine, theirs, None);
+        assert!(reconciled.conflicts.is_empty());
+        assert!(reconciled.data.jobs.is_empty());
+    }
+
+    #[test]
+    fn deleted_externally_while_edited_here_is_a_conflict() {
+        let base = data(vec![job("a", "Applied")]);
+        let mine = data(vec![job("a", "Interview")]);
+        let theirs = data(Vec::new());
+
+        let reconciled = reconcile(&base, &mine, theirs, None);
+        assert_eq!(reconciled.conflicts.len(), 1);
+        assert!(reconciled.conflicts[0].theirs.is_none());
+        assert_eq!(statuses(&reconciled.data), vec![("a", "Interview")]);
+    }
+
+    #[test]
+    fn deleted_here_while_edited_externally_is_a_conflict() {
+        let base = data(vec![job("a", "Applied")]);
+        let mine = data(Vec::new());
+        let theirs = data(vec![job("a", "Offer")]);
+
+        let reconciled = reconcile(&base, &mine, theirs, None);
+        assert_eq!(reconciled.conflicts.len(), 1);
+        assert!(reconciled.conflicts[0].mine.is_none());
+        assert!(reconciled.data.jobs.is_empty());
+    }
+
+    #[test]
+    fn resolve_keeps_mine_by_default() {
+        let base = data(vec![job("a", "Applied")]);
+        let mine = data(vec![job("a", "Interview")]);
+        let theirs = data(vec![job("a", "Rejected")]);
+
+        let resolved = resolve(reconcile(&base, &mine, theirs, None));
+        assert_eq!(statuses(&resolved), vec![("a", "Interview")]);
+    }
+
+    #[test]
+    fn resolve_can_take_the_file_version() {
+        let base = data(vec![job("a", "Applied"), job("b", "Applied")]);
+        let mine = data(vec![job("a", "Interview"), job("b", "Offer")]);
+        let theirs = data(vec![job("a", "Rejected")]);
+
+        let mut reconciled = reconcile(&base, &mine, theirs, None);
+        assert_eq!(reconciled.conflicts.len(), 2);
+        for conflict in &mut reconciled.conflicts {
+            conflict.keep_mine = false;
+        }
+        // "b" was deleted from the file, so taking the file's side removes it
+        assert_eq!(statuses(&resolve(reconciled)), vec![("a", "Rejected")]);
+    }
+
+    #[test]
+    fn resolve_restores_a_job_deleted_here() {
+        let base = data(vec![job("a", "Applied")]);
+        let mine = TrackerData {
+            trash: vec![TrashedJob { job: job("a", "Applied"), deleted_at: "2026-01-01 09:00:00".to_string() }],
+            ..data(Vec::new())
+        };
+        let theirs = data(vec![job("a", "Offer")]);
+
+        let mut reconciled = reconcile(&base, &mine, theirs, None);
+        reconciled.conflicts[0].keep_mine = false;
+        let resolved = resolve(reconciled);
+        assert_eq!(statuses(&resolved), vec![("a", "Offer")]);
+        assert!(resolved.trash.is_empty());
+    }
+
+    #[test]
+    fn other_sections_merge_entry_by_entry() {
+        let base = TrackerData {
+            companies: vec![Company::new("Acme"), Company::new("Globex")],
+            ..data(Vec::new())
+        };
+        let mut mine = base.clone();
+        mine.companies[0].industry = "Fintech".to_string();
+        mine.companies.push(Company::new("Initech"));
+        let mut theirs = base.clone();
+        theirs.companies[1].industry = "Energy".to_string();
+        theirs.companies.push(Company::new("Umbrella"));
+
+        let reconciled = reconcile(&base, &mine, theirs, None);
+        let companies: Vec<(&str, &str)> = reconciled
+            .data
+            .companies
+            .iter()
+            .map(|company| (company.name.as_str(), company.industry.as_str()))
+            .collect();
+        assert_eq!(
+            companies,
+            vec![("Acme", "Fintech"), ("Globex", "Energy"), ("Initech", ""), ("Umbrella", "")]
+        );
+        assert!(reconciled.kept_mine.is_empty());
+    }
+
+    #[test]
+    fn entry_changed_on_both_sides_keeps_mine_and_says_so() {
+        let base = TrackerData {
+            companies: vec![Company::new("Acme")],
+            ..data(Vec::new())
+        };
+        let mut mine = base.clone();
+        mine.companies[0].industry = "Fintech".to_string();
+        let mut theirs = base.clone();
+        theirs.companies[0].industry = "Retail".to_string();
+
+        let reconciled = reconcile(&base, &mine, theirs, None);
+        assert_eq!(reconciled.data.companies[0].industry, "Fintech");
+        assert_eq!(reconciled.kept_mine, vec!["company \"Acme\""]);
+    }
+}
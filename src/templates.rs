@@ -0,0 +1,271 @@
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::company::Contact;
+use crate::data::JobApplication;
+
+// Rendered letters and emails are written here, next to the data file
+pub const EXPORTS_DIR: &str = "exports";
+
+/// Placeholders a template can use, with what each one is filled from
+pub const PLACEHOLDERS: [(&str, &str); 9] = [
+    ("company", "Company name"),
+    ("position", "Job title"),
+    ("date_applied", "Date applied"),
+    ("status", "Current stage"),
+    ("url", "Posting URL"),
+    ("contact_name", "Contact's full name"),
+    ("contact_first_name", "Contact's first name"),
+    ("contact_role", "Contact's role"),
+    ("today", "Today's date"),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TemplateKind {
+    CoverLetter,
+    FollowUp,
+}
+
+impl TemplateKind {
+    pub const ALL: [TemplateKind; 2] = [TemplateKind::CoverLetter, TemplateKind::FollowUp];
+}
+
+impl fmt::Display for TemplateKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                TemplateKind::CoverLetter => "Cover letter",
+                TemplateKind::FollowUp => "Follow-up email",
+            }
+        )
+    }
+}
+
+/// Reusable text with `{{placeholder}}` fields filled in per application
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Template {
+    pub name: String,
+    pub kind: TemplateKind,
+    #[serde(default)]
+    pub subject: String,  // Email subject; unused for cover letters
+    pub body: String,
+}
+
+impl fmt::Display for Template {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl Template {
+    /// Starting points offered while the library is empty
+    pub fn starters() -> Vec<Template> {
+        vec![
+            Template {
+                name: "Follow-up after applying".to_string(),
+                kind: TemplateKind::FollowUp,
+                subject: "Following up on my {{position}} application".to_string(),
+                body: "Hi {{contact_first_name}},\n\n\
+                       I applied for the {{position}} role at {{company}} on {{date_applied}} and wanted to \
+                       follow up. I'm still very interested and would be glad to share anything else that helps.\n\n\
+                       Thanks for your time,"
+                    .to_string(),
+            },
+            Template {
+                name: "Thank you after interview".to_string(),
+                kind: TemplateKind::FollowUp,
+                subject: "Thank you - {{position}} interview".to_string(),
+                body: "Hi {{contact_first_name}},\n\n\
+                       Thank you for taking the time to talk with me about the {{position}} role. \
+                       I enjoyed learning more about the team at {{company}}.\n\n\
+                       Best regards,"
+                    .to_string(),
+            },
+            Template {
+                name: "Cover letter".to_string(),
+                kind: TemplateKind::CoverLetter,
+                subject: String::new(),
+                body: "{{today}}\n\n\
+                       Dear {{contact_name}},\n\n\
+                       I am writing to apply for the {{position}} position at {{company}}.\n\n\
+                       Sincerely,"
+                    .to_string(),
+            },
+        ]
+    }
+}
+
+/// A template filled in for one application
+#[derive(Debug, Clone, Default)]
+pub struct Rendered {
+    pub subject: String,
+    pub body: String,
+    pub unfilled: Vec<String>,  // Placeholders with no value for this application
+}
+
+fn placeholder_value(name: &str, job: &JobApplication, contact: Option<&Contact>) -> Option<String> {
+    let value = match name {
+        "company" => job.company.clone(),
+        "position" => job.position.clone(),
+        "date_applied" => job.date_applied.clone(),
+        "status" => job.status.clone(),
+        "url" => job.url.clone().unwrap_or_default(),
+        "contact_name" => contact?.name.clone(),
+        "contact_first_name" => contact?.name.split_whitespace().next()?.to_string(),
+        "contact_role" => contact?.role.clone(),
+        "today" => Local::now().format("%B %-d, %Y").to_string(),
+        _ => return None,
+    };
+    Some(value).filter(|value| !value.trim().is_empty())
+}
+
+// Replace each {{name}}; unknown or empty placeholders are left in place and reported
+fn fill(text: &str, job: &JobApplication, contact: Option<&Contact>, unfilled: &mut Vec<String>) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find("{{") {
+        let Some(end) = rest[start..].find("}}") else {
+            break;
+        };
+        output.push_str(&rest[..start]);
+
+        let raw = &rest[start..start + end + 2];
+        let name = raw[2..raw.len() - 2].trim();
+        match placeholder_value(name, job, contact) {
+            Some(value) => output.push_str(&value),
+            None => {
+                output.push_str(raw);
+                if !unfilled.iter().any(|n| n == name) {
+                    unfilled.push(name.to_string());
+                }
+            }
+        }
+        rest = &rest[start + end + 2..];
+    }
+
+    output.push_str(rest);
+    output
+}
+
+pub fn render(template: &Template, job: &JobApplication, contact: Option<&Contact>) -> Rendered {
+    let mut unfilled = Vec::new();
+    let subject = fill(&template.subject, job, contact, &mut unfilled);
+    let body = fill(&template.body, job, contact, &mut unfilled);
+    Rendered { subject, body, unfilled }
+}
+
+// Company and template name made safe for use in a file name
+fn export_path(job: &JobApplication, template: &Template, extension: &str) -> PathBuf {
+    let stem: String = format!("{} - {}", job.company, template.name)
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c == ' ' || c == '-' { c } else { '_' })
+        .collect();
+    Path::new(EXPORTS_DIR).join(format!("{}.{}", stem.trim(), extension))
+}
+
+/// Write the rendered text as a plain text file
+pub fn export_txt(rendered: &Rendered, job: &JobApplication, template: &Template) -> std::io::Result<PathBuf> {
+    let path = export_path(job, template, "txt");
+    let contents = if rendered.subject.is_empty() {
+        rendered.body.clone()
+    } else {
+        format!("Subject: {}\n\n{}", rendered.subject, rendered.body)
+    };
+
+    fs::create_dir_all(EXPORTS_DIR)?;
+    fs::write(&path, contents)?;
+    Ok(path)
+}
+
+/// Write the rendered text as an unsent email draft that mail clients can open
+pub fn export_eml(
+    rendered: &Rendered,
+    job: &JobApplication,
+    template: &Template,
+    contact: Option<&Contact>,
+) -> std::io::Result<PathBuf> {
+    let path = export_path(job, template, "eml");
+
+    let mut headers = Vec::new();
+    if let Some(contact) = contact.filter(|c| !c.email.trim().is_empty()) {
+        headers.push(format!("To: {} <{}>", contact.name, contact.email.trim()));
+    }
+    headers.push(format!("Subject: {}", rendered.subject));
+    headers.push(format!("Date: {}", Local::now().to_rfc2822()));
+    headers.push("MIME-Version: 1.0".to_string());
+    headers.push("Content-Type: text/plain; charset=utf-8".to_string());
+    headers.push("Content-Transfer-Encoding: 8bit".to_string());
+    headers.push("X-Unsent: 1".to_string());  // Opens as a draft in Outlook and others
+
+    // Email line endings are CRLF
+    let body = rendered.body.lines().collect::<Vec<_>>().join("\r\n");
+    let contents = format!("{}\r\n\r\n{}\r\n", headers.join("\r\n"), body);
+
+    fs::create_dir_all(EXPORTS_DIR)?;
+    fs::write(&path, contents)?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn job() -> JobApplication {
+        JobApplication {
+            company: "Acme".to_string(),
+            position: "Rust Developer".to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn contact() -> Contact {
+        Contact {
+            name: "Ada Lovelace".to_string(),
+            role: "Hiring Manager".to_string(),
+            email: String::new(),
+        }
+    }
+
+    #[test]
+    fn fills_known_placeholders_with_or_without_spaces() {
+        let mut unfilled = Vec::new();
+        let text = fill("Dear {{contact_first_name}}, re: {{ position }} at {{company}}.", &job(), Some(&contact()), &mut unfilled);
+        assert_eq!(text, "Dear Ada, re: Rust Developer at Acme.");
+        assert!(unfilled.is_empty());
+    }
+
+    #[test]
+    fn unknown_and_empty_placeholders_stay_and_are_reported_once() {
+        let mut unfilled = Vec::new();
+        let text = fill("{{url}} {{nickname}} {{nickname}} {{contact_name}}", &job(), None, &mut unfilled);
+        assert_eq!(text, "{{url}} {{nickname}} {{nickname}} {{contact_name}}");
+        assert_eq!(unfilled, vec!["url", "nickname", "contact_name"]);
+    }
+
+    #[test]
+    fn unclosed_braces_are_left_alone() {
+        let mut unfilled = Vec::new();
+        let text = fill("Hello {{company}} and {{company", &job(), None, &mut unfilled);
+        assert_eq!(text, "Hello Acme and {{company");
+        assert!(unfilled.is_empty());
+    }
+
+    #[test]
+    fn render_collects_unfilled_from_subject_and_body() {
+        let template = Template {
+            name: "Follow-up".to_string(),
+            kind: TemplateKind::FollowUp,
+            subject: "{{position}} - {{contact_role}}".to_string(),
+            body: "Hi {{contact_name}}".to_string(),
+        };
+        let rendered = render(&template, &job(), None);
+        assert_eq!(rendered.subject, "Rust Developer - {{contact_role}}");
+        assert_eq!(rendered.unfilled, vec!["contact_role", "contact_name"]);
+    }
+}
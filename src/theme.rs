@@ -1,4 +1,3 @@
-use crate::data::JobStatus;
 use iced::{Background, Color};
 
 // --- Kraken-inspired Color Palette ---
@@ -62,18 +61,21 @@ pub fn kraken_warning_subtle() -> Color {
     Color::from_rgba(0.945, 0.769, 0.059, 0.3)
 }
 
-// --- Status Colors ---
-pub fn status_color(status: JobStatus) -> Color {
-    match status {
-        JobStatus::Applied => Color::from_rgb(0.22, 0.51, 0.78),      // Blue
-        JobStatus::OA => Color::from_rgb(0.90, 0.62, 0.0),            // Orange
-        JobStatus::Interview => Color::from_rgb(0.129, 0.737, 0.514), // Kraken Green
-        JobStatus::Rejected => Color::from_rgb(0.949, 0.267, 0.267),  // Kraken Red
-        JobStatus::Offer => Color::from_rgb(0.608, 0.349, 0.714),     // Purple
-        JobStatus::Accepted => Color::from_rgb(0.129, 0.737, 0.514),  // Kraken Green
-        JobStatus::Withdrawn => Color::from_rgb(0.5, 0.5, 0.5),       // Gray
-        JobStatus::All => Color::from_rgb(0.4, 0.4, 0.4),             // Gray for "All" filter
+// --- Stage Colors ---
+/// Parse a "#rrggbb" string as used by pipeline stages
+pub fn parse_hex_color(hex: &str) -> Option<Color> {
+    let hex = hex.trim().trim_start_matches('#');
+    if hex.len() != 6 || !hex.is_ascii() {
+        return None;
     }
+
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    Some(Color::from_rgb8(channel(0)?, channel(2)?, channel(4)?))
+}
+
+pub fn color_to_hex(color: Color) -> String {
+    let [r, g, b, _] = color.into_rgba8();
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
 }
 
 // --- Alpha Adjustment Utility Functions ---
@@ -0,0 +1,213 @@
+use std::collections::BTreeMap;
+
+use chrono::{Datelike, Duration, Local, NaiveDate};
+use iced::{
+    mouse,
+    widget::{canvas, column, row, text},
+    Color, Element, Length, Point, Rectangle, Renderer, Size, Theme,
+};
+
+use crate::activity::{self, HEATMAP_WEEKS};
+use crate::message::Message;
+use crate::state::JobTracker;
+use crate::theme::*;
+use crate::ui::stats::panel;
+
+const CELL: f32 = 12.0;
+const GAP: f32 = 3.0;
+const LEFT_MARGIN: f32 = 30.0;  // Room for weekday labels
+const TOP_MARGIN: f32 = 16.0;  // Room for month labels
+const TOOLTIP_LINES: usize = 5;
+
+// Past year of applications per day, with streak counters
+pub fn activity_panel(state: &JobTracker) -> Element<'_, Message, Theme> {
+    let today = Local::now().date_naive();
+    let by_day = activity::applications_by_day(&state.jobs);
+    let streaks = activity::streaks(&by_day, today);
+    let start = activity::heatmap_start(today);
+    let past_year = by_day.range(start..=today).map(|(_, jobs)| jobs.len()).sum::<usize>();
+
+    let days = by_day
+        .range(start..=today)
+        .map(|(&day, jobs)| {
+            let labels = jobs
+                .iter()
+                .filter_map(|&index| state.jobs.get(index))
+                .map(|job| format!("{} - {}", job.company, job.position))
+                .collect();
+            (day, labels)
+        })
+        .collect();
+
+    let counters = row![
+        counter("Past year", past_year.to_string()),
+        counter("Current streak", days_label(streaks.current)),
+        counter("Longest streak", days_label(streaks.longest)),
+    ]
+    .spacing(30);
+
+    let heatmap = Heatmap { days, start, today };
+    let width = LEFT_MARGIN + HEATMAP_WEEKS as f32 * (CELL + GAP);
+    let height = TOP_MARGIN + 7.0 * (CELL + GAP) + 20.0;
+
+    panel(
+        column![
+            counters,
+            canvas(heatmap).width(Length::Fixed(width)).height(Length::Fixed(height)),
+        ]
+        .spacing(12)
+        .into(),
+    )
+}
+
+fn days_label(days: u32) -> String {
+    if days == 1 { "1 day".to_string() } else { format!("{} days", days) }
+}
+
+fn counter<'a>(label: &'a str, value: String) -> Element<'a, Message, Theme> {
+    column![
+        text(label).size(11).style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+        text(value).size(18).style(|_| text::Style { color: Some(kraken_text()) }),
+    ]
+    .spacing(2)
+    .into()
+}
+
+// GitHub-style grid: one column per week, Monday at the top
+struct Heatmap {
+    days: BTreeMap<NaiveDate, Vec<String>>,  // "Company - Position" per application
+    start: NaiveDate,
+    today: NaiveDate,
+}
+
+impl Heatmap {
+    fn cell_origin(&self, day: NaiveDate) -> Point {
+        let offset = (day - self.start).num_days();
+        Point::new(
+            LEFT_MARGIN + (offset / 7) as f32 * (CELL + GAP),
+            TOP_MARGIN + (offset % 7) as f32 * (CELL + GAP),
+        )
+    }
+
+    fn day_at(&self, position: Point) -> Option<NaiveDate> {
+        let week = ((position.x - LEFT_MARGIN) / (CELL + GAP)).floor();
+        let weekday = ((position.y - TOP_MARGIN) / (CELL + GAP)).floor();
+        if week < 0.0 || weekday < 0.0 || week >= HEATMAP_WEEKS as f32 || weekday >= 7.0 {
+            return None;
+        }
+        let day = self.start + Duration::days(week as i64 * 7 + weekday as i64);
+        (day <= self.today).then_some(day)
+    }
+
+    // Shade by count, in four steps like the GitHub contribution graph
+    fn color(count: usize) -> Color {
+        match count {
+            0 => Color::from_rgb(0.14, 0.15, 0.17),
+            1 => with_alpha(kraken_highlight(), 0.35),
+            2 => with_alpha(kraken_highlight(), 0.55),
+            3 | 4 => with_alpha(kraken_highlight(), 0.8),
+            _ => kraken_highlight(),
+        }
+    }
+
+    fn label(frame: &mut canvas::Frame, content: String, position: Point, color: Color) {
+        frame.fill_text(canvas::Text {
+            content,
+            position,
+            color,
+            size: 10.0.into(),
+            ..canvas::Text::default()
+        });
+    }
+
+    fn draw_tooltip(&self, frame: &mut canvas::Frame, day: NaiveDate, cursor: Point) {
+        let labels = self.days.get(&day).map(Vec::as_slice).unwrap_or_default();
+        let mut lines = vec![match labels.len() {
+            0 => format!("No applications on {}", day.format("%a %b %-d, %Y")),
+            1 => format!("1 application on {}", day.format("%a %b %-d, %Y")),
+            n => format!("{} applications on {}", n, day.format("%a %b %-d, %Y")),
+        }];
+        lines.extend(labels.iter().take(TOOLTIP_LINES).cloned());
+        if labels.len() > TOOLTIP_LINES {
+            lines.push(format!("+{} more", labels.len() - TOOLTIP_LINES));
+        }
+
+        // Rough text width is enough to size the box; it stays inside the canvas
+        let longest = lines.iter().map(|line| line.chars().count()).max().unwrap_or(0);
+        let size = Size::new(longest as f32 * 6.0 + 16.0, lines.len() as f32 * 14.0 + 10.0);
+        let bounds = frame.size();
+        let position = Point::new(
+            (cursor.x + 12.0).min(bounds.width - size.width).max(0.0),
+            (cursor.y + 12.0).min(bounds.height - size.height).max(0.0),
+        );
+
+        frame.fill(
+            &canvas::Path::rounded_rectangle(position, size, 4.0.into()),
+            Color::from_rgba(0.05, 0.06, 0.07, 0.95),
+        );
+        for (i, line) in lines.into_iter().enumerate() {
+            let color = if i == 0 { kraken_text() } else { kraken_secondary_text() };
+            Self::label(frame, line, Point::new(position.x + 8.0, position.y + 5.0 + i as f32 * 14.0), color);
+        }
+    }
+}
+
+impl canvas::Program<Message> for Heatmap {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        _theme: &Theme,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> Vec<canvas::Geometry> {
+        let mut frame = canvas::Frame::new(renderer, bounds.size());
+
+        for (row, name) in [(0, "Mon"), (2, "Wed"), (4, "Fri")] {
+            let y = TOP_MARGIN + row as f32 * (CELL + GAP);
+            Self::label(&mut frame, name.to_string(), Point::new(0.0, y), kraken_secondary_text());
+        }
+
+        let mut day = self.start;
+        while day <= self.today {
+            let origin = self.cell_origin(day);
+            if day.day() == 1 {
+                Self::label(&mut frame, day.format("%b").to_string(), Point::new(origin.x, 0.0), kraken_secondary_text());
+            }
+
+            let count = self.days.get(&day).map(Vec::len).unwrap_or(0);
+            frame.fill(
+                &canvas::Path::rounded_rectangle(origin, Size::new(CELL, CELL), 2.0.into()),
+                Self::color(count),
+            );
+            day += Duration::days(1);
+        }
+
+        if let Some(position) = cursor.position_in(bounds) {
+            if let Some(day) = self.day_at(position) {
+                let origin = self.cell_origin(day);
+                frame.stroke(
+                    &canvas::Path::rectangle(origin, Size::new(CELL, CELL)),
+                    canvas::Stroke::default().with_color(kraken_text()).with_width(1.0),
+                );
+                self.draw_tooltip(&mut frame, day, position);
+            }
+        }
+
+        vec![frame.into_geometry()]
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Self::State,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> mouse::Interaction {
+        match cursor.position_in(bounds).and_then(|position| self.day_at(position)) {
+            Some(_) => mouse::Interaction::Pointer,
+            None => mouse::Interaction::default(),
+        }
+    }
+}
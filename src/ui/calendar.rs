@@ -0,0 +1,166 @@
+use chrono::{Datelike, Local, NaiveDate};
+use iced::{
+    widget::{button, column, container, row, text, Space},
+    Alignment, Background, Border, Color, Element, Length, Padding, Shadow, Theme,
+};
+
+use crate::calendar::{self, Entry, EntryKind};
+use crate::data::EventKind;
+use crate::message::Message;
+use crate::state::JobTracker;
+use crate::theme::*;
+use crate::ui::common::*;
+
+// Entries listed in a day cell before the rest are summarised
+const MAX_ENTRIES_PER_DAY: usize = 3;
+
+const WEEKDAYS: [&str; 7] = ["MON", "TUE", "WED", "THU", "FRI", "SAT", "SUN"];
+
+// Month grid of the filtered applications, in place of the list
+pub fn calendar_view(state: &JobTracker) -> Element<'_, Message, Theme> {
+    let month = state.calendar_month;
+    let grid = calendar::month_grid(month);
+    let filtered = state.filtered_jobs();
+    let days = calendar::entries_by_day(filtered.iter().map(|&(index, job, _)| (index, job)), &grid);
+    let today = Local::now().date_naive();
+
+    let navigation = row![
+        button(text("◀").size(13))
+            .style(secondary_button_style)
+            .padding(Padding::from([5, 10]))
+            .on_press(Message::ShiftCalendarMonth(-1)),
+        text(month.format("%B %Y").to_string())
+            .size(18)
+            .width(Length::Fixed(170.0))
+            .align_x(iced::alignment::Horizontal::Center)
+            .style(|_| text::Style { color: Some(kraken_text()) }),
+        button(text("▶").size(13))
+            .style(secondary_button_style)
+            .padding(Padding::from([5, 10]))
+            .on_press(Message::ShiftCalendarMonth(1)),
+        button(text("Today").size(13))
+            .style(secondary_button_style)
+            .padding(Padding::from([5, 10]))
+            .on_press(Message::CalendarToday),
+        Space::with_width(Length::Fill),
+        legend("Applied", kraken_secondary_text()),
+        legend("Interview", kraken_highlight()),
+        legend("Follow-up", kraken_warning()),
+        legend("Deadline", kraken_negative()),
+        text("← → or PgUp/PgDn change month, Home returns to today")
+            .size(11)
+            .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+    ]
+    .spacing(10)
+    .align_y(Alignment::Center);
+
+    let weekdays = WEEKDAYS.iter().fold(row![].spacing(4), |header, day| {
+        header.push(
+            text(*day)
+                .size(11)
+                .width(Length::FillPortion(1))
+                .align_x(iced::alignment::Horizontal::Center)
+                .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+        )
+    });
+
+    let weeks = grid.chunks(7).fold(column![].spacing(4), |weeks, week| {
+        weeks.push(week.iter().fold(row![].spacing(4), |cells, &day| {
+            let entries = days.get(&day).map(Vec::as_slice).unwrap_or_default();
+            cells.push(day_cell(state, day, entries, day.month() == month.month(), day == today))
+        }))
+    });
+
+    column![navigation, weekdays, weeks].spacing(10).into()
+}
+
+fn legend<'a>(label: &'a str, color: Color) -> Element<'a, Message, Theme> {
+    row![
+        container(Space::new(Length::Fixed(8.0), Length::Fixed(8.0))).style(move |_| container::Style {
+            background: Some(Background::Color(color)),
+            border: Border {
+                radius: 4.0.into(),
+                ..Border::default()
+            },
+            ..container::Style::default()
+        }),
+        text(label)
+            .size(11)
+            .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+    ]
+    .spacing(5)
+    .align_y(Alignment::Center)
+    .into()
+}
+
+fn entry_color(state: &JobTracker, entry: &Entry) -> Color {
+    match entry.kind {
+        EntryKind::Applied => state
+            .jobs
+            .get(entry.job)
+            .map(|job| state.pipeline.color(&job.status))
+            .unwrap_or(kraken_secondary_text()),
+        EntryKind::Event(EventKind::Interview) => kraken_highlight(),
+        EntryKind::Event(EventKind::FollowUp) => kraken_warning(),
+        EntryKind::Deadline(_) => kraken_negative(),
+    }
+}
+
+// One day of the grid; clicking it lists that day's applications
+fn day_cell<'a>(
+    state: &'a JobTracker,
+    day: NaiveDate,
+    entries: &[Entry],
+    in_month: bool,
+    is_today: bool,
+) -> Element<'a, Message, Theme> {
+    let number_color = if in_month { kraken_text() } else { with_alpha(kraken_secondary_text(), 0.5) };
+
+    let mut content = column![text(day.day().to_string())
+        .size(12)
+        .style(move |_| text::Style { color: Some(number_color) })]
+    .spacing(3);
+
+    for entry in entries.iter().take(MAX_ENTRIES_PER_DAY) {
+        let color = entry_color(state, entry);
+        content = content.push(
+            text(entry.label.clone())
+                .size(11)
+                .style(move |_| text::Style { color: Some(color) }),
+        );
+    }
+    if entries.len() > MAX_ENTRIES_PER_DAY {
+        content = content.push(
+            text(format!("+{} more", entries.len() - MAX_ENTRIES_PER_DAY))
+                .size(11)
+                .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+        );
+    }
+
+    button(content)
+        .width(Length::FillPortion(1))
+        .height(Length::Fixed(96.0))
+        .padding(6)
+        .style(move |_, status| day_cell_style(status, in_month, is_today))
+        .on_press_maybe((!entries.is_empty()).then_some(Message::OpenDay(day)))
+        .into()
+}
+
+fn day_cell_style(status: button::Status, in_month: bool, is_today: bool) -> button::Style {
+    let background = match status {
+        button::Status::Hovered => Color::from_rgb(0.14, 0.15, 0.17),
+        _ if in_month => Color::from_rgb(0.10, 0.11, 0.13),
+        _ => Color::from_rgb(0.08, 0.09, 0.10),
+    };
+
+    button::Style {
+        background: Some(Background::Color(background)),
+        text_color: kraken_text(),
+        border: Border {
+            color: if is_today { kraken_highlight() } else { kraken_border() },
+            width: 1.0,
+            radius: 4.0.into(),
+        },
+        shadow: Shadow::default(),
+    }
+}
@@ -1,9 +1,27 @@
 use iced::{
-    widget::{button, container, text, text_input},
+    widget::{button, container, text_editor, text_input},
     Border, Background, Color, Shadow, Theme, Vector,
 };
 use crate::theme::*;
-use crate::data::JobStatus;
+use crate::pipeline::StageOutcome;
+
+// Raised panel shown in place of the add form, e.g. the pipeline editor or goals
+pub fn panel_style(_theme: &Theme) -> container::Style {
+    container::Style {
+        background: Some(Background::Color(Color::from_rgb(0.09, 0.10, 0.12))),
+        text_color: Some(kraken_text()),
+        border: Border {
+            color: kraken_highlight_subtle(),
+            width: 1.0,
+            radius: 8.0.into(),
+        },
+        shadow: Shadow {
+            color: Color::from_rgba(0.0, 0.0, 0.0, 0.3),
+            offset: Vector::new(0.0, 3.0),
+            blur_radius: 10.0,
+        },
+    }
+}
 
 pub fn main_background(_theme: &Theme) -> container::Style {
     container::Style {
@@ -27,11 +45,10 @@ pub fn table_header_style(_theme: &Theme) -> container::Style {
             offset: Vector::new(0.0, 1.0),
             blur_radius: 2.0,
         },
-        ..container::Style::default()
     }
 }
 
-pub fn card_style(status: JobStatus, _theme: &Theme) -> container::Style {
+pub fn card_style(outcome: StageOutcome, _theme: &Theme) -> container::Style {
     // Base style for all cards
     let mut style = container::Style {
         background: Some(Background::Color(kraken_card_bg())),
@@ -49,24 +66,24 @@ pub fn card_style(status: JobStatus, _theme: &Theme) -> container::Style {
     };
 
     // Special styling for rejected/withdrawn applications
-    match status {
-        JobStatus::Rejected => {
+    match outcome {
+        StageOutcome::Rejected => {
             style.border.color = kraken_negative_dark();
             style.border.width = 1.0;
             // Add a subtle red tint to the background
             style.background = Some(Background::Color(Color::from_rgb(0.13, 0.106, 0.112)));
         },
-        JobStatus::Withdrawn => {
+        StageOutcome::Withdrawn => {
             style.background = Some(Background::Color(Color::from_rgb(0.088, 0.096, 0.112)));
             style.border.color = Color::from_rgb(0.3, 0.3, 0.3);
         },
-        JobStatus::Accepted => {
+        StageOutcome::Accepted => {
             // Add subtle green tint to accepted jobs
             style.background = Some(Background::Color(Color::from_rgb(0.088, 0.116, 0.112)));
             style.border.color = Color::from_rgba(0.129, 0.737, 0.514, 0.5);
             style.border.width = 1.5;
         },
-        JobStatus::Offer => {
+        StageOutcome::Offer => {
             // Add subtle purple tint to offers
             style.background = Some(Background::Color(Color::from_rgb(0.108, 0.096, 0.132)));
             style.border.color = Color::from_rgba(0.608, 0.349, 0.714, 0.5);
@@ -78,8 +95,7 @@ pub fn card_style(status: JobStatus, _theme: &Theme) -> container::Style {
     style
 }
 
-pub fn status_badge_style(status: JobStatus) -> container::Style {
-    let color = status_color(status);
+pub fn status_badge_style(color: Color, outcome: StageOutcome) -> container::Style {
 
     // Common style for all badges
     let mut style = container::Style {
@@ -95,26 +111,25 @@ pub fn status_badge_style(status: JobStatus) -> container::Style {
             offset: Vector::new(0.0, 2.0),
             blur_radius: 4.0,
         },
-        ..container::Style::default()
     };
 
-    // Special styling for specific statuses
-    match status {
-        JobStatus::Accepted => {
+    // Special styling for specific outcomes
+    match outcome {
+        StageOutcome::Accepted => {
             style.shadow = Shadow {
                 color: Color::from_rgba(0.129, 0.737, 0.514, 0.6),
                 offset: Vector::new(0.0, 0.0),
                 blur_radius: 8.0,
             };
         },
-        JobStatus::Rejected => {
+        StageOutcome::Rejected => {
             style.shadow = Shadow {
                 color: Color::from_rgba(0.949, 0.267, 0.267, 0.4),
                 offset: Vector::new(0.0, 0.0),
                 blur_radius: 6.0,
             };
         },
-        JobStatus::Offer => {
+        StageOutcome::Offer => {
             style.shadow = Shadow {
                 color: Color::from_rgba(0.608, 0.349, 0.714, 0.5),
                 offset: Vector::new(0.0, 0.0),
@@ -313,35 +328,27 @@ pub fn input_style(_theme: &Theme, _status: text_input::Status) -> text_input::S
     }
 }
 
-pub fn company_text_style(status: JobStatus) -> impl Fn(&Theme) -> text::Style {
-    move |_theme| {
-        let base_color = kraken_text();
-
-        // Different styles for different statuses
-        let color = match status {
-            JobStatus::Rejected | JobStatus::Withdrawn => kraken_secondary_text(),
-            _ => base_color,
-        };
-
-        text::Style {
-            color: Some(color),
-        }
+pub fn editor_style(_theme: &Theme, _status: text_editor::Status) -> text_editor::Style {
+    text_editor::Style {
+        background: Background::Color(Color::from_rgb(0.11, 0.12, 0.14)),
+        border: Border {
+            color: kraken_border(),
+            width: 1.0,
+            radius: 6.0.into(),
+        },
+        icon: kraken_secondary_text(),
+        placeholder: kraken_secondary_text(),
+        value: kraken_text(),
+        selection: kraken_highlight_subtle(),
     }
 }
 
-pub fn position_text_style(status: JobStatus) -> impl Fn(&Theme) -> text::Style {
-    move |_theme| {
-        let base_color = kraken_text();
-
-        // Different styles for different statuses
-        let color = match status {
-            JobStatus::Rejected | JobStatus::Withdrawn => kraken_secondary_text(),
-            _ => base_color,
-        };
-
-        text::Style {
-            color: Some(color),
-        }
+// Rejected and withdrawn applications fade into the background
+pub fn row_text_color(outcome: StageOutcome) -> Color {
+    if outcome.is_negative() {
+        kraken_secondary_text()
+    } else {
+        kraken_text()
     }
 }
 
@@ -357,3 +364,23 @@ pub fn filter_section_style(_theme: &Theme) -> container::Style {
         ..container::Style::default()
     }
 }
+
+// Pill-shaped toggle used for view tabs, switches and panel toggles
+pub fn toggle_chip_style(_theme: &Theme, status: button::Status, is_active: bool) -> button::Style {
+    let border_color = if is_active { kraken_highlight() } else { kraken_border() };
+
+    button::Style {
+        background: Some(Background::Color(match (is_active, status) {
+            (true, _) => kraken_highlight_subtle(),
+            (false, button::Status::Hovered) => Color::from_rgb(0.14, 0.15, 0.17),
+            _ => Color::TRANSPARENT,
+        })),
+        text_color: if is_active { kraken_highlight() } else { kraken_text() },
+        border: Border {
+            color: border_color,
+            width: 1.0,
+            radius: 12.0.into(),
+        },
+        shadow: Shadow::default(),
+    }
+}
@@ -0,0 +1,329 @@
+use iced::{
+    widget::{button, column, container, pick_list, row, text, text_input, Space},
+    Alignment, Element, Length, Padding, Theme,
+};
+
+use crate::company::{Company, CompanySize};
+use crate::data::JobApplication;
+use crate::message::Message;
+use crate::state::{CompanyView, JobTracker};
+use crate::theme::*;
+use crate::ui::common::*;
+use crate::ui::form::edit_form;
+use crate::ui::job_row::job_row;
+
+// Registry of known companies, shown in place of the add form
+pub fn companies_panel(state: &JobTracker) -> container::Container<'_, Message, Theme> {
+    let rows = state
+        .companies
+        .iter()
+        .enumerate()
+        .fold(column![].spacing(6), |col, (index, entry)| {
+            let count = state.company_jobs(index).len();
+            col.push(
+                row![
+                    text(&entry.name)
+                        .size(14)
+                        .width(Length::FillPortion(3))
+                        .style(|_| text::Style { color: Some(kraken_text()) }),
+                    text(&entry.industry)
+                        .size(12)
+                        .width(Length::FillPortion(2))
+                        .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+                    text(format!("{} applications", count))
+                        .size(12)
+                        .width(Length::FillPortion(1))
+                        .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+                    button(text("Open").size(11))
+                        .style(secondary_button_style)
+                        .padding(Padding::from([4, 8]))
+                        .on_press(Message::OpenCompany(entry.name.clone())),
+                    button(text("✕").size(11))
+                        .style(delete_button_style)
+                        .padding(Padding::from([4, 8]))
+                        .on_press(Message::RemoveCompany(index)),
+                ]
+                .spacing(10)
+                .align_y(Alignment::Center),
+            )
+        });
+
+    let content = column![
+        row![
+            text("Companies")
+                .size(18)
+                .style(|_| text::Style { color: Some(kraken_text()) }),
+            Space::with_width(Length::Fill),
+            button(text("Register all from applications").size(12))
+                .style(secondary_button_style)
+                .padding(Padding::from([6, 12]))
+                .on_press(Message::RegisterAllCompanies),
+        ]
+        .align_y(Alignment::Center),
+        text("Applications are filed under the registered name. Spellings differing only in case, punctuation or suffixes like \"Inc\" match automatically; add aliases for anything else.")
+            .size(12)
+            .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+        rows,
+        row![
+            text_input("Company name", &state.new_company_input)
+                .padding(8)
+                .style(input_style)
+                .on_input(Message::NewCompanyNameChanged)
+                .on_submit(Message::AddCompany),
+            button(text("Add Company").size(14))
+                .style(secondary_button_style)
+                .padding(Padding::from([8, 15]))
+                .on_press(Message::AddCompany),
+            Space::with_width(Length::Fixed(20.0)),
+            button(text("Done").size(14))
+                .style(primary_button_style)
+                .padding(Padding::from([8, 15]))
+                .on_press(Message::ToggleCompanies),
+        ]
+        .spacing(10)
+        .align_y(Alignment::Center),
+    ]
+    .spacing(15)
+    .padding(20);
+
+    container(content)
+        .width(Length::Fill)
+        .style(panel_style)
+}
+
+// Details, aliases, contacts and every application for one company
+pub fn company_page<'a>(state: &'a JobTracker, view: &'a CompanyView) -> Element<'a, Message, Theme> {
+    match view {
+        CompanyView::Registered(index) => registered_page(state, *index),
+        CompanyView::Unregistered(name) => unregistered_page(state, name),
+    }
+}
+
+// Applications under a name the registry does not know yet, with the option to add it
+fn unregistered_page<'a>(state: &'a JobTracker, name: &'a str) -> Element<'a, Message, Theme> {
+    let applications = state.jobs_matching(&Company::new(name));
+
+    column![
+        container(
+            column![
+                page_header(name),
+                row![
+                    text("This company is not in the registry. Add it to record details, aliases and contacts.")
+                        .size(12)
+                        .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+                    Space::with_width(Length::Fill),
+                    button(text("Add to Registry").size(13))
+                        .style(primary_button_style)
+                        .padding(Padding::from([6, 12]))
+                        .on_press(Message::RegisterCompany(name.to_string())),
+                ]
+                .spacing(10)
+                .align_y(Alignment::Center),
+            ]
+            .spacing(12)
+            .padding(20),
+        )
+        .width(Length::Fill)
+        .style(panel_style),
+        section_label(&format!("APPLICATIONS ({})", applications.len())),
+        application_rows(state, &applications),
+    ]
+    .spacing(15)
+    .into()
+}
+
+fn registered_page(state: &JobTracker, index: usize) -> Element<'_, Message, Theme> {
+    let Some(entry) = state.companies.get(index) else {
+        return Space::with_height(0).into();
+    };
+    let page = &state.company_page;
+
+    let details = column![
+        row![
+            field("Name", text_input("Company name", &page.rename)
+                .padding(8)
+                .style(input_style)
+                .on_input(Message::CompanyRenameChanged)
+                .on_submit(Message::RenameCompany(index))
+                .into()),
+            button(text("Rename").size(12))
+                .style(secondary_button_style)
+                .padding(Padding::from([8, 12]))
+                .on_press_maybe((page.rename.trim() != entry.name).then_some(Message::RenameCompany(index))),
+        ]
+        .spacing(10)
+        .align_y(Alignment::End),
+        row![
+            field("Website", text_input("https://...", entry.website.as_deref().unwrap_or(""))
+                .padding(8)
+                .style(input_style)
+                .on_input(move |website| Message::CompanyWebsiteChanged(index, website))
+                .into()),
+            field("Industry", text_input("e.g. Fintech", &entry.industry)
+                .padding(8)
+                .style(input_style)
+                .on_input(move |industry| Message::CompanyIndustryChanged(index, industry))
+                .into()),
+            field("Size", pick_list(CompanySize::ALL, entry.size, move |size| {
+                Message::CompanySizeSelected(index, size)
+            })
+                .placeholder("Unknown")
+                .padding(8)
+                .into()),
+        ]
+        .spacing(15),
+        field("Notes", text_input("Culture, tech stack, anything worth remembering", &entry.notes)
+            .padding(8)
+            .style(input_style)
+            .on_input(move |notes| Message::CompanyNotesChanged(index, notes))
+            .into()),
+    ]
+    .spacing(12);
+
+    let aliases = entry
+        .aliases
+        .iter()
+        .enumerate()
+        .fold(row![].spacing(6), |chips, (alias_index, alias)| {
+            chips.push(
+                button(row![text(alias).size(12), text("✕").size(10)].spacing(6).align_y(Alignment::Center))
+                    .style(|theme, status| toggle_chip_style(theme, status, false))
+                    .padding(Padding::from([3, 8]))
+                    .on_press(Message::RemoveAlias(index, alias_index)),
+            )
+        })
+        .push(
+            text_input("Add alias", &page.alias)
+                .padding(6)
+                .width(Length::Fixed(180.0))
+                .style(input_style)
+                .on_input(Message::AliasInputChanged)
+                .on_submit(Message::AddAlias(index)),
+        )
+        .wrap();
+
+    let contacts = entry
+        .contacts
+        .iter()
+        .enumerate()
+        .fold(column![].spacing(6), |col, (contact_index, contact)| {
+            col.push(
+                row![
+                    text(&contact.name)
+                        .size(13)
+                        .width(Length::FillPortion(2))
+                        .style(|_| text::Style { color: Some(kraken_text()) }),
+                    text(&contact.role)
+                        .size(12)
+                        .width(Length::FillPortion(2))
+                        .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+                    text(&contact.email)
+                        .size(12)
+                        .width(Length::FillPortion(3))
+                        .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+                    button(text("✕").size(11))
+                        .style(delete_button_style)
+                        .padding(Padding::from([4, 8]))
+                        .on_press(Message::RemoveContact(index, contact_index)),
+                ]
+                .spacing(10)
+                .align_y(Alignment::Center),
+            )
+        })
+        .push(
+            row![
+                text_input("Name", &page.contact.name)
+                    .padding(6)
+                    .width(Length::FillPortion(2))
+                    .style(input_style)
+                    .on_input(Message::ContactNameChanged),
+                text_input("Role", &page.contact.role)
+                    .padding(6)
+                    .width(Length::FillPortion(2))
+                    .style(input_style)
+                    .on_input(Message::ContactRoleChanged),
+                text_input("Email", &page.contact.email)
+                    .padding(6)
+                    .width(Length::FillPortion(3))
+                    .style(input_style)
+                    .on_input(Message::ContactEmailChanged)
+                    .on_submit(Message::AddContact(index)),
+                button(text("Add").size(12))
+                    .style(secondary_button_style)
+                    .padding(Padding::from([6, 12]))
+                    .on_press(Message::AddContact(index)),
+            ]
+            .spacing(10)
+            .align_y(Alignment::Center),
+        );
+
+    let applications = state.company_jobs(index);
+
+    column![
+        container(
+            column![
+                page_header(&entry.name),
+                details,
+                section_label("ALIASES"),
+                aliases,
+                section_label("CONTACTS"),
+                contacts,
+            ]
+            .spacing(12)
+            .padding(20),
+        )
+        .width(Length::Fill)
+        .style(panel_style),
+        section_label(&format!("APPLICATIONS ({})", applications.len())),
+        application_rows(state, &applications),
+    ]
+    .spacing(15)
+    .into()
+}
+
+fn page_header(name: &str) -> Element<'_, Message, Theme> {
+    row![
+        text(name)
+            .size(20)
+            .style(|_| text::Style { color: Some(kraken_text()) }),
+        Space::with_width(Length::Fill),
+        button(text("Back to all applications").size(13))
+            .style(secondary_button_style)
+            .padding(Padding::from([6, 12]))
+            .on_press(Message::CloseCompany),
+    ]
+    .align_y(Alignment::Center)
+    .into()
+}
+
+fn application_rows<'a>(state: &'a JobTracker, applications: &[(usize, &'a JobApplication)]) -> Element<'a, Message, Theme> {
+    applications
+        .iter()
+        .fold(column![].spacing(12), |col, &(job_index, job)| {
+            if state.editing_index == Some(job_index) {
+                col.push(edit_form(job_index, state))
+            } else {
+                col.push(job_row(state, job_index, job))
+            }
+        })
+        .into()
+}
+
+fn field<'a>(label: &'a str, input: Element<'a, Message, Theme>) -> Element<'a, Message, Theme> {
+    column![
+        text(label)
+            .size(12)
+            .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+        input,
+    ]
+    .spacing(5)
+    .width(Length::FillPortion(1))
+    .into()
+}
+
+fn section_label(label: &str) -> Element<'static, Message, Theme> {
+    text(label.to_string())
+        .size(12)
+        .style(|_| text::Style { color: Some(kraken_secondary_text()) })
+        .into()
+}
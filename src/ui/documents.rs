@@ -0,0 +1,159 @@
+use iced::{
+    widget::{button, column, container, pick_list, row, text, text_input, Space},
+    Alignment, Element, Length, Padding, Theme,
+};
+
+use crate::documents::{Document, DocumentKind};
+use crate::message::Message;
+use crate::state::{FormState, JobTracker};
+use crate::theme::*;
+use crate::ui::common::*;
+
+// Resume and cover letter versions with how each has performed, shown in place of the add form
+pub fn documents_panel(state: &JobTracker) -> container::Container<'_, Message, Theme> {
+    let sections = DocumentKind::ALL.into_iter().fold(column![].spacing(15), |col, kind| {
+        let rows = state
+            .documents
+            .iter()
+            .filter(|doc| doc.kind == kind)
+            .fold(column![].spacing(6), |rows, doc| rows.push(document_row(state, doc)));
+
+        col.push(
+            column![
+                text(kind.to_string().to_uppercase())
+                    .size(12)
+                    .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+                rows,
+            ]
+            .spacing(8),
+        )
+    });
+
+    let new = &state.new_document;
+    let content = column![
+        text("Documents")
+            .size(18)
+            .style(|_| text::Style { color: Some(kraken_text()) }),
+        text("Files are copied into the library, so later edits to the originals don't change what was sent.")
+            .size(12)
+            .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+        sections,
+        row![
+            text_input("/path/to/resume.pdf", &new.path)
+                .padding(8)
+                .width(Length::FillPortion(3))
+                .style(input_style)
+                .on_input(Message::DocumentPathChanged),
+            text_input("Version name, e.g. Resume v3 (backend)", &new.name)
+                .padding(8)
+                .width(Length::FillPortion(2))
+                .style(input_style)
+                .on_input(Message::DocumentNameChanged)
+                .on_submit(Message::AddDocument),
+            pick_list(DocumentKind::ALL, Some(new.kind), Message::DocumentKindSelected).padding(8),
+            button(text("Add to Library").size(14))
+                .style(secondary_button_style)
+                .padding(Padding::from([8, 15]))
+                .on_press(Message::AddDocument),
+        ]
+        .spacing(10)
+        .align_y(Alignment::Center),
+        row![
+            Space::with_width(Length::Fill),
+            button(text("Done").size(14))
+                .style(primary_button_style)
+                .padding(Padding::from([8, 15]))
+                .on_press(Message::ToggleDocuments),
+        ],
+    ]
+    .spacing(15)
+    .padding(20);
+
+    container(content)
+        .width(Length::Fill)
+        .style(panel_style)
+}
+
+fn document_row<'a>(state: &'a JobTracker, doc: &'a Document) -> Element<'a, Message, Theme> {
+    let (sent, responded) = doc.response_stats(&state.jobs, &state.pipeline);
+    let stats = if sent == 0 {
+        "Not sent yet".to_string()
+    } else {
+        format!(
+            "Sent {} · {} responded ({:.0}%)",
+            sent,
+            responded,
+            responded as f32 / sent as f32 * 100.0
+        )
+    };
+
+    row![
+        text(&doc.name)
+            .size(14)
+            .width(Length::FillPortion(3))
+            .style(|_| text::Style { color: Some(kraken_text()) }),
+        text(&doc.added)
+            .size(12)
+            .width(Length::FillPortion(1))
+            .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+        text(stats)
+            .size(12)
+            .width(Length::FillPortion(2))
+            .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+        button(text("Open").size(11))
+            .style(secondary_button_style)
+            .padding(Padding::from([4, 8]))
+            .on_press(Message::OpenDocument(doc.id.clone())),
+        button(text("✕").size(11))
+            .style(delete_button_style)
+            .padding(Padding::from([4, 8]))
+            .on_press(Message::RemoveDocument(doc.id.clone())),
+    ]
+    .spacing(10)
+    .align_y(Alignment::Center)
+    .into()
+}
+
+// Resume and cover letter pickers for the add and edit forms
+pub fn document_pickers<'a>(state: &'a JobTracker, form: &'a FormState) -> Element<'a, Message, Theme> {
+    if state.documents.is_empty() {
+        return Space::with_height(0).into();
+    }
+
+    DocumentKind::ALL
+        .into_iter()
+        .fold(row![].spacing(15), |pickers, kind| {
+            let selected = match kind {
+                DocumentKind::Resume => &form.resume,
+                DocumentKind::CoverLetter => &form.cover_letter,
+            };
+            let selected = selected.as_deref().and_then(|id| state.document(id)).cloned();
+
+            pickers.push(
+                column![
+                    text(format!("{} sent", kind))
+                        .size(12)
+                        .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+                    row![
+                        pick_list(state.documents_of(kind), selected.clone(), move |doc| {
+                            Message::DocumentPicked(kind, Some(doc.id))
+                        })
+                        .placeholder("None")
+                        .padding(8)
+                        .width(Length::Fill),
+                    ]
+                    .push_maybe(selected.map(|_| {
+                        button(text("✕").size(11))
+                            .style(secondary_button_style)
+                            .padding(Padding::from([6, 8]))
+                            .on_press(Message::DocumentPicked(kind, None))
+                    }))
+                    .spacing(6)
+                    .align_y(Alignment::Center),
+                ]
+                .spacing(5)
+                .width(Length::FillPortion(1)),
+            )
+        })
+        .into()
+}
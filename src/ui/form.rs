@@ -1,13 +1,16 @@
 use iced::{
-    widget::{button, column, container, pick_list, row, text, text_input, Space},
-    Background, Border, Color, Length, Padding, Shadow, Theme, Vector,
+    widget::{button, checkbox, column, container, pick_list, row, text, text_editor, text_input, Space},
+    Alignment, Background, Border, Color, Element, Length, Padding, Shadow, Theme, Vector,
 };
 
-use crate::data::JobStatus;
+use crate::company;
 use crate::message::Message;
+use crate::data::{DeadlineKind, EventKind, ReasonCategory, Sponsorship, WorkMode};
+use crate::pipeline::Pipeline;
 use crate::state::{FormState, JobTracker};
 use crate::theme::*;
 use crate::ui::common::*;
+use crate::ui::documents::document_pickers;
 
 // Extract the pick list style to avoid duplication
 fn pick_list_style(_theme: &Theme, _status: pick_list::Status) -> pick_list::Style {
@@ -29,15 +32,37 @@ pub fn add_form(state: &JobTracker) -> container::Container<'_, Message, Theme>
         return container(Space::with_height(0)).width(Length::Fill);
     }
 
-    let status_options = [
-        JobStatus::Applied,
-        JobStatus::OA,
-        JobStatus::Interview,
-        JobStatus::Rejected,
-        JobStatus::Offer,
-        JobStatus::Accepted,
-        JobStatus::Withdrawn,
-    ];
+    // Warn before saving something that looks like an application already tracked
+    let duplicates = state.form_duplicates();
+    let duplicate_warning: Element<'_, Message, Theme> = if duplicates.is_empty() {
+        Space::with_height(0).into()
+    } else {
+        duplicates.iter().fold(
+            column![text("This looks like an application you already have:")
+                .size(12)
+                .style(|_| text::Style { color: Some(kraken_warning()) })]
+            .spacing(6),
+            |col, &(index, reason)| {
+                let job = &state.jobs[index];
+                col.push(
+                    row![
+                        text(format!("{} — {} ({}, {})", job.company, job.position, job.status, reason))
+                            .size(12)
+                            .width(Length::Fill)
+                            .style(|_| text::Style { color: Some(kraken_text()) }),
+                        button(text("Edit existing").size(11))
+                            .style(secondary_button_style)
+                            .padding(Padding::from([3, 8]))
+                            .on_press(Message::StartEditing(index)),
+                    ]
+                    .spacing(10)
+                    .align_y(Alignment::Center),
+                )
+            },
+        )
+        .into()
+    };
+    let add_label = if duplicates.is_empty() { "Add Application" } else { "Add Anyway" };
 
     let form_content = column![
         text("Add New Application")
@@ -53,7 +78,8 @@ pub fn add_form(state: &JobTracker) -> container::Container<'_, Message, Theme>
                 text_input("Company name", &state.form.company)
                     .padding(8)
                     .style(input_style)
-                    .on_input(Message::CompanyChanged)
+                    .on_input(Message::CompanyChanged),
+                company_suggestions(state, &state.form.company),
             ]
             .spacing(5)
             .width(Length::FillPortion(1)),
@@ -91,8 +117,8 @@ pub fn add_form(state: &JobTracker) -> container::Container<'_, Message, Theme>
                     .size(12)
                     .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
                 pick_list(
-                    status_options,
-                    state.form.status,
+                    state.pipeline.names(),
+                    state.form.status.clone(),
                     Message::StatusSelected
                 )
                 .padding(8)
@@ -103,18 +129,28 @@ pub fn add_form(state: &JobTracker) -> container::Container<'_, Message, Theme>
         ]
         .spacing(15),
 
+        // Closure reason, prompted for when rejecting or withdrawing
+        reason_section(&state.form, &state.pipeline),
+
         // Row 3: Notes
         column![
             text("Notes")
                 .size(12)
                 .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
-            text_input("Additional notes about the application", &state.form.notes)
+            text_editor(&state.form.notes)
+                .placeholder("Additional notes (Markdown supported)")
+                .height(Length::Fixed(120.0))
                 .padding(8)
-                .style(input_style)
-                .on_input(Message::NotesChanged)
+                .style(editor_style)
+                .on_action(Message::NotesEdited)
         ]
         .spacing(5),
 
+        description_section(&state.form),
+        document_pickers(state, &state.form),
+        schedule_section(&state.form),
+        deadline_section(&state.form),
+
         // Row 4: URL
         column![
             text("URL (Optional)")
@@ -127,6 +163,11 @@ pub fn add_form(state: &JobTracker) -> container::Container<'_, Message, Theme>
         ]
         .spacing(5),
 
+        source_section(state, &state.form),
+        location_section(&state.form),
+
+        duplicate_warning,
+
         // Action buttons
         row![
             Space::with_width(Length::Fill),
@@ -134,7 +175,7 @@ pub fn add_form(state: &JobTracker) -> container::Container<'_, Message, Theme>
                 .style(secondary_button_style)
                 .padding(Padding::from([10, 20]))
                 .on_press(Message::ResetForm),
-            button(text("Add Application").size(14))
+            button(text(add_label).size(14))
                 .style(primary_button_style)
                 .padding(Padding::from([10, 20]))
                 .on_press(Message::AddJob),
@@ -149,16 +190,262 @@ pub fn add_form(state: &JobTracker) -> container::Container<'_, Message, Theme>
         .style(form_style)
 }
 
-pub fn edit_form(index: usize, form: &FormState) -> container::Container<'_, Message, Theme> {
-    let status_options = [
-        JobStatus::Applied,
-        JobStatus::OA,
-        JobStatus::Interview,
-        JobStatus::Rejected,
-        JobStatus::Offer,
-        JobStatus::Accepted,
-        JobStatus::Withdrawn,
-    ];
+// Job description editor with import from a saved copy of the posting
+fn description_section(form: &FormState) -> Element<'_, Message, Theme> {
+    column![
+        text("Job Description")
+            .size(12)
+            .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+        text_editor(&form.description)
+            .placeholder("Paste the posting so it survives after the listing closes")
+            .height(Length::Fixed(140.0))
+            .padding(8)
+            .style(editor_style)
+            .on_action(Message::DescriptionEdited),
+        row![
+            text_input("Or import a saved .html, .pdf or .txt file: /path/to/posting.html", &form.description_path)
+                .padding(8)
+                .style(input_style)
+                .on_input(Message::DescriptionPathChanged)
+                .on_submit(Message::ImportDescription),
+            button(text(if form.importing_description { "Importing..." } else { "Import" }).size(13))
+                .style(secondary_button_style)
+                .padding(Padding::from([8, 15]))
+                .on_press_maybe((!form.importing_description).then_some(Message::ImportDescription)),
+        ]
+        .spacing(10)
+        .align_y(Alignment::Center),
+    ]
+    .spacing(5)
+    .into()
+}
+
+// Where the application came from: a configured source with one click, or anything typed
+fn source_section<'a>(state: &'a JobTracker, form: &'a FormState) -> Element<'a, Message, Theme> {
+    let chips = state.settings.sources.iter().fold(row![].spacing(6), |chips, source| {
+        let selected = form.source.trim().eq_ignore_ascii_case(source);
+        chips.push(
+            button(text(source).size(11))
+                .style(move |theme, status| toggle_chip_style(theme, status, selected))
+                .padding(Padding::from([3, 8]))
+                .on_press(Message::SourceChanged(if selected { String::new() } else { source.clone() })),
+        )
+    });
+
+    column![
+        text("Source (Optional)")
+            .size(12)
+            .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+        row![
+            text_input("e.g. LinkedIn, a referral from Sam", &form.source)
+                .padding(8)
+                .width(Length::FillPortion(1))
+                .style(input_style)
+                .on_input(Message::SourceChanged),
+            container(chips.wrap()).width(Length::FillPortion(2)),
+        ]
+        .spacing(10)
+        .align_y(Alignment::Center),
+    ]
+    .spacing(5)
+    .into()
+}
+
+// Where the role is based, how it is worked and whether a visa can be sponsored
+fn location_section(form: &FormState) -> Element<'_, Message, Theme> {
+    let work_modes = WorkMode::ALL.into_iter().fold(row![].spacing(6), |chips, mode| {
+        let selected = form.work_mode == Some(mode);
+        chips.push(
+            button(text(mode.to_string()).size(11))
+                .style(move |theme, status| toggle_chip_style(theme, status, selected))
+                .padding(Padding::from([3, 8]))
+                .on_press(Message::WorkModeSelected(mode)),
+        )
+    });
+    let sponsorship = Sponsorship::ALL.into_iter().fold(row![].spacing(6), |chips, option| {
+        let selected = form.visa_sponsorship == Some(option);
+        chips.push(
+            button(text(option.to_string()).size(11))
+                .style(move |theme, status| toggle_chip_style(theme, status, selected))
+                .padding(Padding::from([3, 8]))
+                .on_press(Message::SponsorshipSelected(option)),
+        )
+    });
+
+    column![
+        text("Location (Optional)")
+            .size(12)
+            .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+        row![
+            text_input("City", &form.city)
+                .padding(8)
+                .style(input_style)
+                .on_input(Message::CityChanged),
+            text_input("State / region", &form.region)
+                .padding(8)
+                .style(input_style)
+                .on_input(Message::RegionChanged),
+            text_input("Country", &form.country)
+                .padding(8)
+                .style(input_style)
+                .on_input(Message::CountryChanged),
+        ]
+        .spacing(10),
+        row![work_modes, Space::with_width(Length::Fixed(20.0)), sponsorship]
+            .align_y(Alignment::Center),
+    ]
+    .spacing(5)
+    .into()
+}
+
+// Interviews and follow-ups already scheduled, plus inputs for another
+fn schedule_section(form: &FormState) -> Element<'_, Message, Theme> {
+    let scheduled = form.events.iter().fold(column![].spacing(4), |list, event| {
+        let when = if event.time.is_empty() {
+            event.date.clone()
+        } else {
+            format!("{} {}", event.date, event.time)
+        };
+        list.push(
+            row![
+                text(when)
+                    .size(13)
+                    .width(Length::Fixed(130.0))
+                    .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+                text(event.label())
+                    .size(13)
+                    .width(Length::Fill)
+                    .style(|_| text::Style { color: Some(kraken_text()) }),
+                button(text("✕").size(11))
+                    .style(secondary_button_style)
+                    .padding(Padding::from([3, 8]))
+                    .on_press(Message::RemoveEvent(event.id.clone())),
+            ]
+            .spacing(10)
+            .align_y(Alignment::Center),
+        )
+    });
+
+    let new = &form.new_event;
+    column![
+        text("Interviews & Follow-ups")
+            .size(12)
+            .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+        scheduled,
+        row![
+            pick_list(EventKind::ALL, Some(new.kind), Message::EventKindSelected)
+                .padding(8)
+                .style(pick_list_style),
+            text_input("YYYY-MM-DD", &new.date)
+                .padding(8)
+                .width(Length::Fixed(120.0))
+                .style(input_style)
+                .on_input(Message::EventDateChanged),
+            text_input("HH:MM", &new.time)
+                .padding(8)
+                .width(Length::Fixed(80.0))
+                .style(input_style)
+                .on_input(Message::EventTimeChanged),
+            text_input("e.g. Technical round", &new.title)
+                .padding(8)
+                .style(input_style)
+                .on_input(Message::EventTitleChanged)
+                .on_submit(Message::AddEvent),
+            button(text("Schedule").size(13))
+                .style(secondary_button_style)
+                .padding(Padding::from([8, 15]))
+                .on_press(Message::AddEvent),
+        ]
+        .spacing(10)
+        .align_y(Alignment::Center),
+    ]
+    .spacing(5)
+    .into()
+}
+
+// Close dates and offer expirations, plus inputs for another
+fn deadline_section(form: &FormState) -> Element<'_, Message, Theme> {
+    let deadlines = form.deadlines.iter().fold(column![].spacing(4), |list, deadline| {
+        let when = if deadline.time.is_empty() {
+            deadline.date.clone()
+        } else {
+            format!("{} {}", deadline.date, deadline.time)
+        };
+        list.push(
+            row![
+                text(when)
+                    .size(13)
+                    .width(Length::Fixed(130.0))
+                    .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+                text(deadline.label())
+                    .size(13)
+                    .width(Length::Fill)
+                    .style(|_| text::Style { color: Some(kraken_text()) }),
+                button(text("✕").size(11))
+                    .style(secondary_button_style)
+                    .padding(Padding::from([3, 8]))
+                    .on_press(Message::RemoveDeadline(deadline.id.clone())),
+            ]
+            .spacing(10)
+            .align_y(Alignment::Center),
+        )
+    });
+
+    let new = &form.new_deadline;
+    column![
+        text("Deadlines")
+            .size(12)
+            .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+        deadlines,
+        row![
+            pick_list(DeadlineKind::ALL, Some(new.kind), Message::DeadlineKindSelected)
+                .padding(8)
+                .style(pick_list_style),
+            text_input("YYYY-MM-DD", &new.date)
+                .padding(8)
+                .width(Length::Fixed(120.0))
+                .style(input_style)
+                .on_input(Message::DeadlineDateChanged),
+            text_input("HH:MM", &new.time)
+                .padding(8)
+                .width(Length::Fixed(80.0))
+                .style(input_style)
+                .on_input(Message::DeadlineTimeChanged),
+            text_input("e.g. Verbal offer, written copy to follow", &new.note)
+                .padding(8)
+                .style(input_style)
+                .on_input(Message::DeadlineNoteChanged)
+                .on_submit(Message::AddDeadline),
+            button(text("Add Deadline").size(13))
+                .style(secondary_button_style)
+                .padding(Padding::from([8, 15]))
+                .on_press(Message::AddDeadline),
+        ]
+        .spacing(10)
+        .align_y(Alignment::Center),
+    ]
+    .spacing(5)
+    .into()
+}
+
+// Registered companies matching what has been typed, so applications land under one name
+fn company_suggestions<'a>(state: &'a JobTracker, typed: &str) -> Element<'a, Message, Theme> {
+    company::suggestions(&state.companies, typed)
+        .into_iter()
+        .fold(row![].spacing(6), |chips, name| {
+            chips.push(
+                button(text(name).size(11))
+                    .style(|theme, status| toggle_chip_style(theme, status, false))
+                    .padding(Padding::from([3, 8]))
+                    .on_press(Message::CompanySuggestionPicked(name.to_string())),
+            )
+        })
+        .wrap()
+        .into()
+}
+
+pub fn edit_form(index: usize, state: &JobTracker) -> container::Container<'_, Message, Theme> {
+    let form = &state.edit_form;
 
     let edit_form_content = column![
         text(format!("Edit Application: {}", form.company))
@@ -174,7 +461,8 @@ pub fn edit_form(index: usize, form: &FormState) -> container::Container<'_, Mes
                 text_input("Company name", &form.company)
                     .padding(8)
                     .style(input_style)
-                    .on_input(Message::CompanyChanged)
+                    .on_input(Message::CompanyChanged),
+                company_suggestions(state, &form.company),
             ]
             .spacing(5)
             .width(Length::FillPortion(1)),
@@ -212,8 +500,8 @@ pub fn edit_form(index: usize, form: &FormState) -> container::Container<'_, Mes
                     .size(12)
                     .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
                 pick_list(
-                    status_options,
-                    form.status,
+                    state.pipeline.names(),
+                    form.status.clone(),
                     Message::StatusSelected
                 )
                 .padding(8)
@@ -224,18 +512,29 @@ pub fn edit_form(index: usize, form: &FormState) -> container::Container<'_, Mes
         ]
         .spacing(15),
 
+        // Status change details, only while the status differs from the saved one
+        transition_section(state, index),
+        reason_section(form, &state.pipeline),
+
         // Row 3: Notes
         column![
             text("Notes")
                 .size(12)
                 .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
-            text_input("Additional notes about the application", &form.notes)
+            text_editor(&form.notes)
+                .placeholder("Additional notes (Markdown supported)")
+                .height(Length::Fixed(120.0))
                 .padding(8)
-                .style(input_style)
-                .on_input(Message::NotesChanged)
+                .style(editor_style)
+                .on_action(Message::NotesEdited)
         ]
         .spacing(5),
 
+        description_section(form),
+        document_pickers(state, form),
+        schedule_section(form),
+        deadline_section(form),
+
         // Row 4: URL
         column![
             text("URL (Optional)")
@@ -248,12 +547,19 @@ pub fn edit_form(index: usize, form: &FormState) -> container::Container<'_, Mes
         ]
         .spacing(5),
 
+        source_section(state, form),
+        location_section(form),
+
         // Action buttons - with edit-specific actions
         row![
-            button(text("Delete").size(14))
+            button(text("Move to Trash").size(14))
                 .style(delete_button_style)
                 .padding(Padding::from([10, 20]))
                 .on_press(Message::DeleteJob(index)),
+            button(text(if state.jobs[index].archived { "Unarchive" } else { "Archive" }).size(14))
+                .style(secondary_button_style)
+                .padding(Padding::from([10, 20]))
+                .on_press(Message::ToggleArchived(index)),
             Space::with_width(Length::Fill),
             button(text("Cancel").size(14))
                 .style(secondary_button_style)
@@ -274,6 +580,99 @@ pub fn edit_form(index: usize, form: &FormState) -> container::Container<'_, Mes
         .style(edit_form_style)
 }
 
+fn reason_section<'a>(form: &'a FormState, pipeline: &Pipeline) -> Element<'a, Message, Theme> {
+    let closing = form
+        .status
+        .as_deref()
+        .is_some_and(|status| pipeline.outcome(status).is_negative());
+    if !closing {
+        return Space::with_height(0).into();
+    }
+
+    // Only open stages make sense as the furthest point reached
+    let reached_options: Vec<String> = pipeline
+        .stages
+        .iter()
+        .filter(|stage| !stage.terminal)
+        .map(|stage| stage.name.clone())
+        .collect();
+
+    column![
+        row![
+            column![
+                text("Stage Reached")
+                    .size(12)
+                    .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+                pick_list(reached_options, form.reason_stage.clone(), Message::ReasonStageSelected)
+                    .placeholder("Current stage")
+                    .padding(8)
+                    .style(pick_list_style)
+            ]
+            .spacing(5)
+            .width(Length::FillPortion(1)),
+            column![
+                text("Reason")
+                    .size(12)
+                    .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+                pick_list(ReasonCategory::ALL, form.reason_category, Message::ReasonCategorySelected)
+                    .placeholder("Why did it end?")
+                    .padding(8)
+                    .style(pick_list_style)
+            ]
+            .spacing(5)
+            .width(Length::FillPortion(1)),
+        ]
+        .spacing(15),
+        text_input("Details (optional)", &form.reason_details)
+            .padding(8)
+            .style(input_style)
+            .on_input(Message::ReasonDetailsChanged),
+    ]
+    .spacing(8)
+    .into()
+}
+
+fn transition_section(state: &JobTracker, index: usize) -> Element<'_, Message, Theme> {
+    let form = &state.edit_form;
+    let previous = state.jobs.get(index).map(|job| job.status.as_str()).unwrap_or_default();
+    let next = form.status.as_deref().unwrap_or_default();
+
+    if previous == next {
+        return Space::with_height(0).into();
+    }
+
+    let mut section = column![
+        text(format!("Change note ({} → {})", previous, next))
+            .size(12)
+            .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+        text_input("Why did the status change?", &form.transition_note)
+            .padding(8)
+            .style(input_style)
+            .on_input(Message::TransitionNoteChanged),
+    ]
+    .spacing(5);
+
+    // Disallowed moves can still be saved, but only deliberately
+    if let Err(err) = state.pipeline.validate_transition(previous, next) {
+        section = section.push(
+            row![
+                text(err)
+                    .size(12)
+                    .style(|_| text::Style { color: Some(kraken_warning()) }),
+                Space::with_width(Length::Fill),
+                checkbox("Force status change", form.force_transition)
+                    .on_toggle(Message::ForceTransitionToggled)
+                    .size(16)
+                    .text_size(12),
+            ]
+            .spacing(10)
+            .align_y(Alignment::Center),
+        );
+    }
+
+    section.into()
+}
+
 fn form_style(_theme: &Theme) -> container::Style {
     container::Style {
         background: Some(Background::Color(Color::from_rgb(0.09, 0.10, 0.12))),
@@ -288,7 +687,6 @@ fn form_style(_theme: &Theme) -> container::Style {
             offset: Vector::new(0.0, 3.0),
             blur_radius: 10.0,
         },
-        ..container::Style::default()
     }
 }
 
@@ -0,0 +1,200 @@
+use chrono::Local;
+use iced::{
+    widget::{button, column, container, pick_list, progress_bar, row, text, text_input, Space},
+    Alignment, Background, Border, Color, Element, Length, Padding, Theme,
+};
+
+use crate::goals::{self, GoalMetric, GoalPeriod};
+use crate::message::Message;
+use crate::state::JobTracker;
+use crate::theme::*;
+use crate::ui::common::*;
+
+// Networking log entries listed in the panel, newest first
+const RECENT_NETWORKING: usize = 8;
+
+// One progress bar per goal for the current week or month, shown in the header
+pub fn goal_progress(state: &JobTracker) -> Element<'_, Message, Theme> {
+    if state.settings.goals.is_empty() {
+        return Space::with_height(0).into();
+    }
+
+    let today = Local::now().date_naive();
+    let bars = state.settings.goals.iter().fold(row![].spacing(25), |bars, goal| {
+        let done = goals::progress(goal, &state.jobs, &state.networking, today);
+        let met = done >= goal.target;
+        bars.push(
+            column![
+                text(format!("{} {}: {}/{}", goal.metric, goal.period.current_label(), done, goal.target))
+                    .size(12)
+                    .style(move |_| text::Style {
+                        color: Some(if met { kraken_highlight() } else { kraken_secondary_text() })
+                    }),
+                progress_bar(0.0..=goal.target as f32, done.min(goal.target) as f32)
+                    .height(Length::Fixed(6.0))
+                    .width(Length::Fixed(180.0))
+                    .style(move |_| progress_bar::Style {
+                        background: Background::Color(Color::from_rgb(0.14, 0.15, 0.17)),
+                        bar: Background::Color(if met { kraken_highlight() } else { kraken_warning() }),
+                        border: Border {
+                            radius: 3.0.into(),
+                            ..Border::default()
+                        },
+                    }),
+            ]
+            .spacing(4),
+        )
+    });
+
+    let tracks_networking = state
+        .settings
+        .goals
+        .iter()
+        .any(|goal| goal.metric == GoalMetric::NetworkingMessages);
+
+    row![bars, Space::with_width(Length::Fill)]
+        .push_maybe(tracks_networking.then(|| {
+            button(text("+1 Networking Message").size(12))
+                .style(secondary_button_style)
+                .padding(Padding::from([5, 10]))
+                .on_press(Message::LogNetworkingContact)
+        }))
+        .spacing(10)
+        .align_y(Alignment::Center)
+        .padding(Padding::from([0, 20]))
+        .into()
+}
+
+// Goal settings, hit/missed history and the networking log, shown in place of the add form
+pub fn goals_panel(state: &JobTracker) -> container::Container<'_, Message, Theme> {
+    let today = Local::now().date_naive();
+
+    let goal_rows = state.settings.goals.iter().enumerate().fold(column![].spacing(10), |rows, (index, goal)| {
+        let history = goals::history(goal, &state.jobs, &state.networking, today);
+        let hit = history.iter().filter(|(_, _, hit)| *hit).count();
+
+        let squares = history.into_iter().fold(row![].spacing(3), |squares, (_, done, hit)| {
+            squares.push(
+                container(text(done.to_string()).size(10))
+                    .width(Length::Fixed(22.0))
+                    .center_x(Length::Fixed(22.0))
+                    .padding(Padding::from([2, 0]))
+                    .style(move |_| container::Style {
+                        background: Some(Background::Color(if hit {
+                            kraken_highlight_subtle()
+                        } else {
+                            Color::from_rgba(0.95, 0.27, 0.27, 0.15)
+                        })),
+                        text_color: Some(if hit { kraken_highlight() } else { kraken_negative() }),
+                        border: Border {
+                            radius: 3.0.into(),
+                            ..Border::default()
+                        },
+                        ..container::Style::default()
+                    }),
+            )
+        });
+
+        rows.push(
+            row![
+                text(format!("{} {} {}", goal.target, goal.metric.to_string().to_lowercase(), goal.period.to_string().to_lowercase()))
+                    .size(14)
+                    .width(Length::Fixed(260.0))
+                    .style(|_| text::Style { color: Some(kraken_text()) }),
+                squares,
+                text(format!("Hit {} of the last {}", hit, goals::HISTORY_PERIODS))
+                    .size(12)
+                    .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+                Space::with_width(Length::Fill),
+                button(text("✕").size(11))
+                    .style(delete_button_style)
+                    .padding(Padding::from([4, 8]))
+                    .on_press(Message::RemoveGoal(index)),
+            ]
+            .spacing(12)
+            .align_y(Alignment::Center),
+        )
+    });
+
+    let new_goal = row![
+        text_input("Target", &state.new_goal_target)
+            .padding(8)
+            .width(Length::Fixed(80.0))
+            .style(input_style)
+            .on_input(Message::NewGoalTargetChanged)
+            .on_submit(Message::AddGoal),
+        pick_list(GoalMetric::ALL, Some(state.new_goal.metric), Message::NewGoalMetricSelected).padding(8),
+        pick_list(GoalPeriod::ALL, Some(state.new_goal.period), Message::NewGoalPeriodSelected).padding(8),
+        button(text("Set Goal").size(14))
+            .style(secondary_button_style)
+            .padding(Padding::from([8, 15]))
+            .on_press(Message::AddGoal),
+    ]
+    .spacing(10)
+    .align_y(Alignment::Center);
+
+    let recent = state
+        .networking
+        .iter()
+        .enumerate()
+        .rev()
+        .take(RECENT_NETWORKING)
+        .fold(column![].spacing(4), |list, (index, entry)| {
+            list.push(
+                row![
+                    text(&entry.date)
+                        .size(12)
+                        .width(Length::Fixed(90.0))
+                        .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+                    text(&entry.note).size(12).width(Length::Fill),
+                    button(text("✕").size(10))
+                        .style(secondary_button_style)
+                        .padding(Padding::from([2, 6]))
+                        .on_press(Message::RemoveNetworkingEntry(index)),
+                ]
+                .spacing(10)
+                .align_y(Alignment::Center),
+            )
+        });
+
+    let content = column![
+        text("Goals")
+            .size(18)
+            .style(|_| text::Style { color: Some(kraken_text()) }),
+        text("Applications count on their applied date and interviews on their scheduled date. Green periods met the target.")
+            .size(12)
+            .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+        goal_rows,
+        new_goal,
+        text("NETWORKING LOG")
+            .size(12)
+            .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+        row![
+            text_input("Who was it to? (optional)", &state.networking_note)
+                .padding(8)
+                .style(input_style)
+                .on_input(Message::NetworkingNoteChanged)
+                .on_submit(Message::LogNetworkingContact),
+            button(text("Log Message").size(14))
+                .style(secondary_button_style)
+                .padding(Padding::from([8, 15]))
+                .on_press(Message::LogNetworkingContact),
+        ]
+        .spacing(10)
+        .align_y(Alignment::Center),
+        recent,
+        row![
+            Space::with_width(Length::Fill),
+            button(text("Done").size(14))
+                .style(primary_button_style)
+                .padding(Padding::from([8, 15]))
+                .on_press(Message::ToggleGoals),
+        ],
+    ]
+    .spacing(15)
+    .padding(20);
+
+    container(content)
+        .width(Length::Fill)
+        .style(panel_style)
+}
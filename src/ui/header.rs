@@ -1,15 +1,18 @@
+use chrono::Local;
 use iced::{
     Background, Border, Color, Length, Padding, Shadow, Theme, Vector, alignment,
     widget::{Space, button, column, container, pick_list, row, text, text_input},
 };
 
-use crate::data::JobStatus;
+use crate::data::{GroupBy, LocationFilter, SourceFilter, StatusFilter, WorkModeFilter};
 use crate::message::Message;
-use crate::state::JobTracker;
+use crate::state::{DatePreset, JobTracker};
 use crate::theme::*;
 use crate::ui::common::{
     delete_button_style, filter_section_style, input_style, secondary_button_style,
+    toggle_chip_style,
 };
+use crate::ui::goals::goal_progress;
 
 pub fn app_header(state: &JobTracker) -> container::Container<'_, Message, Theme> {
     // Top row: Title, main action buttons, and job count
@@ -21,6 +24,26 @@ pub fn app_header(state: &JobTracker) -> container::Container<'_, Message, Theme
                 color: Some(kraken_text())
             }),
         Space::with_width(Length::Fill),
+        button(text("Documents").size(14))
+            .style(move |theme, status| toggle_chip_style(theme, status, state.show_documents))
+            .padding(Padding::from([8, 15]))
+            .on_press(Message::ToggleDocuments),
+        button(text("Templates").size(14))
+            .style(move |theme, status| toggle_chip_style(theme, status, state.show_templates))
+            .padding(Padding::from([8, 15]))
+            .on_press(Message::ToggleTemplates),
+        button(text("Goals").size(14))
+            .style(move |theme, status| toggle_chip_style(theme, status, state.show_goals))
+            .padding(Padding::from([8, 15]))
+            .on_press(Message::ToggleGoals),
+        button(text("Companies").size(14))
+            .style(move |theme, status| toggle_chip_style(theme, status, state.show_companies))
+            .padding(Padding::from([8, 15]))
+            .on_press(Message::ToggleCompanies),
+        button(text("Pipeline").size(14))
+            .style(move |theme, status| toggle_chip_style(theme, status, state.show_pipeline_editor))
+            .padding(Padding::from([8, 15]))
+            .on_press(Message::TogglePipelineEditor),
         // Right side: Primary actions
         if state.editing_index.is_some() {
             // When editing, show a button to finish editing first - styled to stand out
@@ -61,7 +84,17 @@ pub fn app_header(state: &JobTracker) -> container::Container<'_, Message, Theme
     // Second row: Save status, save/reload buttons
     let status_row = row![
         // Left: Save status - Now checks has_unsaved_changes in both branches
-        if state.has_unsaved_changes {
+        if state.save_deferred && state.has_unsaved_changes {
+            text(if state.sync.is_some() {
+                "Save deferred until the conflict is resolved"
+            } else {
+                "Save deferred until changes to the data file are merged in"
+            })
+            .size(12)
+            .style(|_| text::Style {
+                color: Some(kraken_warning()),
+            })
+        } else if state.has_unsaved_changes {
             text("Unsaved changes").size(12).style(|_| text::Style {
                 color: Some(kraken_warning()),
             })
@@ -78,6 +111,23 @@ pub fn app_header(state: &JobTracker) -> container::Container<'_, Message, Theme
             })
         },
         Space::with_width(Length::Fill),
+        // Calendar export, and whether the .ics next to the data file follows every save
+        if let Some(path) = &state.calendar_exported {
+            text(format!("Calendar saved to {}", path)).size(12).style(|_| text::Style {
+                color: Some(kraken_secondary_text()),
+            })
+        } else {
+            text("")
+        },
+        button(text("Export .ics").size(14))
+            .style(secondary_button_style)
+            .padding(Padding::from([8, 15]))
+            .on_press(Message::ExportCalendar),
+        button(text("Live .ics").size(14))
+            .style(move |theme, status| toggle_chip_style(theme, status, state.settings.live_calendar))
+            .padding(Padding::from([8, 15]))
+            .on_press(Message::ToggleLiveCalendar),
+        Space::with_width(Length::Fixed(10.0)),
         // Right: Save/Reload buttons with enhanced padding
         button(text("Save").size(14))
             .style(secondary_button_style)
@@ -103,40 +153,29 @@ pub fn app_header(state: &JobTracker) -> container::Container<'_, Message, Theme
             })
         }),
         pick_list(
-            [
-                JobStatus::All,
-                JobStatus::Applied,
-                JobStatus::OA,
-                JobStatus::Interview,
-                JobStatus::Rejected,
-                JobStatus::Offer,
-                JobStatus::Accepted,
-                JobStatus::Withdrawn
-            ],
-            state.filter_status,
+            StatusFilter::options(&state.pipeline),
+            Some(state.filter_status.clone()),
             Message::FilterStatusChanged
         )
         .padding(5)
-        .style(|_theme, _status| {
-            let alpha = if state.editing_index.is_some() { 0.6 } else { 1.0 };
-            pick_list::Style {
-                text_color: with_alpha(kraken_text(), alpha),
-                placeholder_color: with_alpha(kraken_secondary_text(), alpha),
-                handle_color: with_alpha(kraken_secondary_text(), alpha),
-                background: Background::Color(with_alpha(Color::from_rgb(0.12, 0.14, 0.16), alpha)),
-                border: Border {
-                    color: with_alpha(kraken_border(), alpha),
-                    width: 1.0,
-                    radius: 4.0.into(),
-                },
-            }
-        }),
+        .style(move |theme, status| filter_pick_list_style(theme, status, state.editing_index.is_some())),
+        pick_list(
+            SourceFilter::options(&state.settings.sources, &state.jobs),
+            Some(state.filter_source.clone()),
+            Message::FilterSourceChanged
+        )
+        .padding(5)
+        .style(move |theme, status| filter_pick_list_style(theme, status, state.editing_index.is_some())),
         Space::with_width(Length::Fixed(15.0)),
         text_input("Search jobs...", &state.search_query)
             .padding(5)
             .width(Length::Fixed(200.0))
             .style(input_style)
             .on_input(Message::SearchQueryChanged),
+        button(text("Best match").size(12))
+            .style(move |theme, status| toggle_chip_style(theme, status, state.sort_by_relevance))
+            .padding(Padding::from([5, 12]))
+            .on_press(Message::ToggleRelevanceSort),
         Space::with_width(Length::Fill),
         button(text("Clear").size(14))
             .style(secondary_button_style)
@@ -147,6 +186,135 @@ pub fn app_header(state: &JobTracker) -> container::Container<'_, Message, Theme
         .align_y(alignment::Vertical::Center)
         .padding(Padding::from([10, 20]));
 
+    // Location row: where and how the role is worked, and how the list is sectioned
+    let location_row = row![
+        text("Where:").size(14).style(|_| text::Style {
+            color: Some(kraken_secondary_text())
+        }),
+        pick_list(
+            LocationFilter::options(&state.jobs),
+            Some(state.filter_location.clone()),
+            Message::FilterLocationChanged
+        )
+        .padding(5)
+        .style(move |theme, status| filter_pick_list_style(theme, status, state.editing_index.is_some())),
+        pick_list(
+            WorkModeFilter::ALL,
+            Some(state.filter_work_mode),
+            Message::FilterWorkModeChanged
+        )
+        .padding(5)
+        .style(move |theme, status| filter_pick_list_style(theme, status, state.editing_index.is_some())),
+        button(text("Sponsors visa").size(12))
+            .style(move |theme, status| toggle_chip_style(theme, status, state.sponsorship_only))
+            .padding(Padding::from([5, 12]))
+            .on_press(Message::ToggleSponsorshipFilter),
+        Space::with_width(Length::Fill),
+        pick_list(GroupBy::ALL, Some(state.group_by), Message::GroupByChanged)
+            .padding(5)
+            .style(move |theme, status| filter_pick_list_style(theme, status, false)),
+    ]
+        .spacing(10)
+        .align_y(alignment::Vertical::Center)
+        .padding(Padding::from([0, 20]));
+
+    // Date row: applied date range with presets, and a staleness threshold
+    let preset_buttons = DatePreset::ALL.into_iter().fold(row![].spacing(6), |presets, preset| {
+        presets.push(
+            button(text(preset.label()).size(12))
+                .style(secondary_button_style)
+                .padding(Padding::from([4, 10]))
+                .on_press(Message::DatePresetSelected(preset)),
+        )
+    });
+
+    let date_row = row![
+        text("Applied:").size(14).style(|_| text::Style {
+            color: Some(kraken_secondary_text())
+        }),
+        text_input("From YYYY-MM-DD", &state.date_from)
+            .padding(5)
+            .width(Length::Fixed(130.0))
+            .style(input_style)
+            .on_input(Message::DateFromChanged),
+        text("to").size(14).style(|_| text::Style {
+            color: Some(kraken_secondary_text())
+        }),
+        text_input("To YYYY-MM-DD", &state.date_to)
+            .padding(5)
+            .width(Length::Fixed(130.0))
+            .style(input_style)
+            .on_input(Message::DateToChanged),
+        preset_buttons,
+        Space::with_width(Length::Fill),
+        text("No activity for").size(14).style(|_| text::Style {
+            color: Some(kraken_secondary_text())
+        }),
+        text_input("N", &state.stale_days)
+            .padding(5)
+            .width(Length::Fixed(50.0))
+            .style(input_style)
+            .on_input(Message::StaleDaysChanged),
+        text("days").size(14).style(|_| text::Style {
+            color: Some(kraken_secondary_text())
+        }),
+    ]
+        .spacing(10)
+        .align_y(alignment::Vertical::Center)
+        .padding(Padding::from([0, 20]).bottom(10));
+
+    // Views row: built-in and saved filter/sort presets as tabs
+    let view_tabs = state.all_views().into_iter().fold(
+        row![text("Views:").size(14).style(|_| text::Style {
+            color: Some(kraken_secondary_text())
+        })]
+        .spacing(6)
+        .align_y(alignment::Vertical::Center),
+        |tabs, view| {
+            let is_active = state.is_view_active(&view);
+            let is_builtin = state.settings.saved_views.iter().all(|v| v.name != view.name);
+
+            let tab = button(text(view.name.clone()).size(13))
+                .style(move |theme, status| toggle_chip_style(theme, status, is_active))
+                .padding(Padding::from([5, 12]))
+                .on_press(Message::ApplyView(view.name.clone()));
+
+            if is_builtin {
+                tabs.push(tab)
+            } else {
+                tabs.push(
+                    row![
+                        tab,
+                        button(text("✕").size(10))
+                            .style(delete_button_style)
+                            .padding(Padding::from([4, 6]))
+                            .on_press(Message::DeleteView(view.name)),
+                    ]
+                    .spacing(2)
+                    .align_y(alignment::Vertical::Center),
+                )
+            }
+        },
+    );
+
+    let views_row = row![
+        view_tabs,
+        Space::with_width(Length::Fill),
+        text_input("View name", &state.view_name_input)
+            .padding(5)
+            .width(Length::Fixed(150.0))
+            .style(input_style)
+            .on_input(Message::ViewNameChanged)
+            .on_submit(Message::SaveCurrentView),
+        button(text("Save View").size(14))
+            .style(secondary_button_style)
+            .padding(Padding::from([6, 12]))
+            .on_press(Message::SaveCurrentView),
+    ]
+        .spacing(10)
+        .align_y(alignment::Vertical::Center)
+        .padding(Padding::from([0, 20]).bottom(10));
+
     // Error display (only shows when there's an error)
     let error_display = if let Some(error) = &state.error_message {
         container(
@@ -201,12 +369,49 @@ pub fn app_header(state: &JobTracker) -> container::Container<'_, Message, Theme
         container(Space::with_height(Length::Fixed(0.0))).width(Length::Fill)
     };
 
+    // Warning for deadlines of active applications that fall due within two days
+    let urgent = state.urgent_deadlines();
+    let deadline_warning = if urgent.is_empty() {
+        container(Space::with_height(Length::Fixed(0.0))).width(Length::Fill)
+    } else {
+        let now = Local::now().naive_local();
+        let lines = urgent.into_iter().fold(column![].spacing(3), |lines, (job, deadline, _)| {
+            lines.push(
+                text(format!(
+                    "⚠ {} - {} at {}: {}",
+                    deadline.label(),
+                    job.position,
+                    job.company,
+                    deadline.countdown(now).unwrap_or_default()
+                ))
+                .size(12)
+                .style(|_| text::Style {
+                    color: Some(kraken_negative())
+                }),
+            )
+        });
+        container(lines)
+            .width(Length::Fill)
+            .padding(Padding::from([8, 20]))
+            .style(|_| container::Style {
+                background: Some(Background::Color(Color::from_rgba(0.95, 0.27, 0.27, 0.1))),
+                border: Border {
+                    color: kraken_negative(),
+                    width: 1.0,
+                    radius: 0.0.into(),
+                },
+                ..container::Style::default()
+            })
+    };
+
     // Combine all sections into the header with improved spacing
     let header_content = column![
         top_row,
         status_row,
+        goal_progress(state),
+        deadline_warning,
         editing_notification,
-        container(filter_row)
+        container(column![filter_row, location_row, date_row, views_row])
             .width(Length::Fill)
             .style(filter_section_style),
         error_display,
@@ -219,6 +424,22 @@ pub fn app_header(state: &JobTracker) -> container::Container<'_, Message, Theme
         .style(header_style)
 }
 
+// Filter pick lists are dimmed while an application is being edited
+fn filter_pick_list_style(_theme: &Theme, _status: pick_list::Status, dimmed: bool) -> pick_list::Style {
+    let alpha = if dimmed { 0.6 } else { 1.0 };
+    pick_list::Style {
+        text_color: with_alpha(kraken_text(), alpha),
+        placeholder_color: with_alpha(kraken_secondary_text(), alpha),
+        handle_color: with_alpha(kraken_secondary_text(), alpha),
+        background: Background::Color(with_alpha(Color::from_rgb(0.12, 0.14, 0.16), alpha)),
+        border: Border {
+            color: with_alpha(kraken_border(), alpha),
+            width: 1.0,
+            radius: 4.0.into(),
+        },
+    }
+}
+
 fn toggle_form_button_style(_theme: &Theme, status: button::Status) -> button::Style {
     match status {
         button::Status::Hovered => button::Style {
@@ -325,6 +546,5 @@ fn header_style(_theme: &Theme) -> container::Style {
             offset: Vector::new(0.0, 2.0),
             blur_radius: 8.0,
         },
-        ..container::Style::default()
     }
 }
@@ -1,94 +1,184 @@
+use std::ops::Range;
+
+use chrono::Local;
 use iced::{
-    widget::{button, container, row, text},
-    alignment, Alignment, Border, Element, Length, Padding, Shadow, Theme, Color
+    widget::{button, column, container, markdown, rich_text, row, scrollable, span, text},
+    alignment, Alignment, Background, Border, Element, Length, Padding, Shadow, Theme, Color
 };
 
-use crate::data::{JobApplication, JobStatus};
+use crate::data::{DeadlineKind, JobApplication, DEADLINE_SOON_DAYS, DEADLINE_URGENT_HOURS, MAX_PRIORITY};
+use crate::documents::DocumentKind;
 use crate::message::Message;
+use crate::metrics;
 use crate::theme::*;
 use crate::ui::common::*;
-use crate::state::{SortColumn, SortOrder};
+use crate::ui::keywords::keyword_section;
+use crate::ui::templates::template_preview;
+use crate::state::{JobTracker, SortColumn, SortKey, SortOrder};
+
+pub fn job_row<'a>(
+    state: &'a JobTracker,
+    index: usize,
+    job: &'a JobApplication,
+) -> Element<'a, Message, Theme> {
+    let outcome = state.pipeline.outcome(&job.status);
+    let stage_color = state.pipeline.color(&job.status);
+    let highlights = state.search_highlights(job);
+    let notes_panel = (state.expanded_notes == Some(index))
+        .then_some(state.notes_markdown.as_slice());
+    let reading = state.reading_description == Some(index);
+    let preview = state.template_preview.as_ref().filter(|preview| preview.job == index);
+
+    // Only the date part of the last update timestamp fits in the column
+    let updated_date = job
+        .last_updated
+        .as_deref()
+        .and_then(|ts| ts.split_whitespace().next())
+        .unwrap_or("-");
 
-pub fn job_row(index: usize, job: &JobApplication) -> Element<'_, Message, Theme> {
-    let status = job.status;
+    // Aging indicator: how long the application has been in its current stage
+    let stage_age = metrics::days_in_stage(job, Local::now().date_naive()).map(|days| {
+        let color = match days {
+            _ if !state.pipeline.is_active(&job.status) => kraken_secondary_text(),
+            d if d >= metrics::STALE_DAYS => kraken_negative(),
+            d if d >= metrics::AGING_DAYS => kraken_warning(),
+            _ => kraken_secondary_text(),
+        };
+        (days, color)
+    });
 
     // Enhanced status badge with glow effect for special statuses
     let status_badge = container(
-        text(job.status.to_string())
+        text(&job.status)
             .size(13)
             .style(|_| text::Style { color: Some(Color::WHITE) })
             .width(Length::Fill)
             .align_x(alignment::Horizontal::Center),
     )
         .padding(Padding::from([6, 12]))
-        .style(move |_| status_badge_style(job.status))
+        .style(move |_| status_badge_style(stage_color, outcome))
         .width(Length::FillPortion(1));
 
-    // Action buttons for editing
+    // Action buttons for editing and writing from a template, plus the job description reader when one is saved
+    let writing = preview.is_some();
     let action_buttons = row![
         button(text("Edit").size(13))
             .style(edit_button_style)
             .padding(Padding::from([5, 10]))
             .on_press(Message::StartEditing(index)),
+        button(text("Write").size(13))
+            .style(move |theme, status| toggle_chip_style(theme, status, writing))
+            .padding(Padding::from([5, 8]))
+            .on_press(Message::ToggleTemplatePreview(index)),
     ]
+        .push_maybe((!job.description.is_empty()).then(|| {
+            button(text("JD").size(13))
+                .style(move |theme, status| toggle_chip_style(theme, status, reading))
+                .padding(Padding::from([5, 8]))
+                .on_press(Message::ToggleDescription(index))
+        }))
         .spacing(8)
         .align_y(Alignment::Center)
         .width(Length::FillPortion(1));
 
-    // Enhanced row design with special styling for rejected/withdrawn jobs
-    let row_content = if let Some(url) = &job.url {
-        row![
-            text(&job.company)
-                .size(14)
-                .width(Length::FillPortion(2))
-                .style(company_text_style(status)),
-            button(
-                text(&job.position)
-                    .size(14)
-            )
+    let highlights = highlights.cloned().unwrap_or_default();
+    let base_color = row_text_color(outcome);
+
+    // Position links to the posting when a URL is available
+    let position: Element<'a, Message, Theme> = if let Some(url) = &job.url {
+        button(highlighted_text(&job.position, &highlights.position, kraken_highlight()).size(14))
             .style(link_button_style)
             .padding(Padding::from([5, 10]))
             .on_press(Message::OpenUrl(url.clone()))
-            .width(Length::FillPortion(3)),
-            text(&job.date_applied)
-                .size(14)
-                .width(Length::FillPortion(2))
-                .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
-            status_badge,
-            text(&job.notes)
-                .size(14)
-                .width(Length::FillPortion(4))
-                .style(|_| text::Style { color: Some(kraken_text()) }),
-            action_buttons,
-        ]
+            .into()
     } else {
-        row![
-            text(&job.company)
-                .size(14)
-                .width(Length::FillPortion(2))
-                .style(company_text_style(status)),
-            text(&job.position)
-                .size(14)
-                .width(Length::FillPortion(3))
-                .style(position_text_style(status)),
-            text(&job.date_applied)
-                .size(14)
-                .width(Length::FillPortion(2))
+        highlighted_text(&job.position, &highlights.position, base_color)
+            .size(14)
+            .into()
+    };
+
+    // Location, work mode and sponsorship chips under the position
+    let chips: Vec<String> = [
+        (!job.location.is_empty()).then(|| job.location.to_string()),
+        job.work_mode.map(|mode| mode.to_string()),
+        job.visa_sponsorship.map(|sponsorship| sponsorship.to_string()),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    let position = column![position]
+        .push_maybe((!chips.is_empty()).then(|| {
+            chips.into_iter().fold(row![].spacing(6), |row, chip| row.push(info_chip(chip))).wrap()
+        }))
+        .spacing(4)
+        .width(Length::FillPortion(3));
+
+    // Only the first line of the notes fits in the row; the rest lives in the panel
+    let first_line = job.notes.lines().next().unwrap_or("");
+    let preview_highlights: Vec<Range<usize>> = highlights
+        .notes
+        .iter()
+        .filter(|r| r.end <= first_line.len())
+        .cloned()
+        .collect();
+    let has_more = job.notes.trim_end().len() > first_line.len();
+
+    let mut notes_preview = row![
+        highlighted_text(first_line, &preview_highlights, base_color)
+            .size(14)
+            .width(Length::Fill)
+    ]
+        .spacing(6)
+        .align_y(Alignment::Center)
+        .width(Length::FillPortion(2));
+
+    // History beyond the initial status also lives in the expanded panel
+    let has_details = !job.notes.is_empty()
+        || job.history.len() > 1
+        || job.closure_reason.is_some()
+        || !job.other_urls.is_empty()
+        || job.resume.is_some()
+        || job.cover_letter.is_some();
+    if has_details {
+        notes_preview = notes_preview.push(
+            button(text(if notes_panel.is_some() { "▴" } else if has_more { "▾ more" } else { "▾" }).size(11))
+                .style(link_button_style)
+                .padding(Padding::from([2, 6]))
+                .on_press(Message::ToggleNotes(index)),
+        );
+    }
+
+    // Enhanced row design with special styling for rejected/withdrawn jobs
+    let row_content = row![
+        // Company opens its page with every application and contact there
+        button(highlighted_text(&job.company, &highlights.company, base_color).size(14))
+            .style(link_button_style)
+            .padding(Padding::from([5, 0]))
+            .on_press(Message::OpenCompany(job.company.clone()))
+            .width(Length::FillPortion(2)),
+        position,
+        text(&job.date_applied)
+            .size(14)
+            .width(Length::FillPortion(2))
+            .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+        status_badge,
+        column![
+            text(updated_date)
+                .size(12)
                 .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
-            status_badge,
-            text(&job.notes)
-                .size(14)
-                .width(Length::FillPortion(4))
-                .style(move |_| text::Style {
-                    color: Some(if status == JobStatus::Rejected || status == JobStatus::Withdrawn {
-                        kraken_secondary_text()
-                    } else {
-                        kraken_text()
-                    })
-                }),
-            action_buttons,
         ]
-    };
+        .push_maybe(stage_age.map(|(days, color)| {
+            text(format!("{}d in stage", days))
+                .size(11)
+                .style(move |_| text::Style { color: Some(color) })
+        }))
+        .spacing(2)
+        .width(Length::FillPortion(1)),
+        priority_control(index, job),
+        deadline_badges(job),
+        notes_preview,
+        action_buttons,
+    ];
 
     // Format the row content
     let content = row_content
@@ -96,67 +186,303 @@ pub fn job_row(index: usize, job: &JobApplication) -> Element<'_, Message, Theme
         .align_y(Alignment::Center)
         .padding(Padding::new(18.0));
 
+    // Rendered Markdown notes below the row when expanded
+    let body = match notes_panel {
+        Some(items) => column![
+            content,
+            container(
+                column![
+                    markdown::view(items, markdown::Settings::with_text_size(14), notes_markdown_style())
+                        .map(|url| Message::OpenUrl(url.to_string())),
+                    other_urls(job),
+                    sent_documents(state, job),
+                    status_history(job),
+                ]
+                .spacing(12)
+            )
+                .width(Length::Fill)
+                .padding(Padding::from([12, 18]))
+                .style(notes_panel_style),
+        ],
+        None => column![content],
+    }
+    .push_maybe(reading.then(|| description_reader(state, index, job, &highlights.description)))
+    .push_maybe(preview.map(|preview| template_preview(state, preview)));
+
     // Return a container with the job application
-    container(content)
+    container(body)
         .width(Length::Fill)
-        .style(move |theme| card_style(status, theme))
+        .style(move |theme| card_style(outcome, theme))
         .into()
 }
 
-pub fn table_header(sort_column: SortColumn, sort_order: SortOrder) -> container::Container<'static, Message, Theme> {
-    // Company header with sorting triangle
-    let company_text = row![
-        text("COMPANY").size(13),
-        text(match (sort_column, sort_order) {
-            (SortColumn::Company, SortOrder::Ascending) => "▲",
-            (SortColumn::Company, SortOrder::Descending) => "▼",
-            _ => "⋮" // Neutral state
-        }).size(9)
+// Clickable stars for the rating, and a pin toggle, both applied without entering edit mode
+fn priority_control<'a>(index: usize, job: &JobApplication) -> Element<'a, Message, Theme> {
+    let rating = job.priority.unwrap_or(0);
+    let stars = (1..=MAX_PRIORITY).fold(row![], |stars, n| {
+        let lit = n <= rating;
+        stars.push(
+            button(text(if lit { "★" } else { "☆" }).size(14))
+                .style(move |_theme, status| button::Style {
+                    text_color: match status {
+                        button::Status::Hovered => kraken_highlight_hover(),
+                        _ if lit => kraken_warning(),
+                        _ => kraken_secondary_text(),
+                    },
+                    background: None,
+                    border: Border::default(),
+                    shadow: Shadow::default(),
+                })
+                .padding(Padding::from([0, 1]))
+                .on_press(Message::SetPriority(index, n)),
+        )
+    });
+
+    let pinned = job.pinned;
+    column![
+        stars,
+        button(text(if pinned { "Pinned" } else { "Pin" }).size(11))
+            .style(move |theme, status| toggle_chip_style(theme, status, pinned))
+            .padding(Padding::from([2, 8]))
+            .on_press(Message::TogglePin(index)),
     ]
-        .spacing(5);
+    .spacing(4)
+    .width(Length::FillPortion(1))
+    .into()
+}
 
-    // Use button instead of container for interactivity
-    let company_header = button(company_text)
-        .width(Length::FillPortion(2))
-        .style(|_theme, _status| button::Style {
-            text_color: kraken_secondary_text(),
-            background: None,
-            border: Border::default(),
-            shadow: Shadow::default(),
+// A countdown per deadline, turning amber within a week and red within two days
+fn deadline_badges<'a>(job: &JobApplication) -> Element<'a, Message, Theme> {
+    let now = Local::now().naive_local();
+    job.deadlines
+        .iter()
+        .filter_map(|deadline| Some((deadline, deadline.due()?, deadline.countdown(now)?)))
+        .fold(column![].spacing(3), |badges, (deadline, due, countdown)| {
+            let hours = (due - now).num_hours();
+            let color = match hours {
+                _ if due < now => kraken_secondary_text(),
+                h if h < DEADLINE_URGENT_HOURS => kraken_negative(),
+                h if h < DEADLINE_SOON_DAYS * 24 => kraken_warning(),
+                _ => kraken_highlight(),
+            };
+            badges.push(
+                container(text(format!("{} · {}", short_kind(deadline.kind), countdown)).size(11))
+                    .padding(Padding::from([2, 8]))
+                    .style(move |_| container::Style {
+                        background: Some(Background::Color(with_alpha(color, 0.15))),
+                        text_color: Some(color),
+                        border: Border {
+                            color: with_alpha(color, 0.5),
+                            width: 1.0,
+                            radius: 10.0.into(),
+                        },
+                        ..container::Style::default()
+                    }),
+            )
         })
-        .on_press(Message::SortBy(SortColumn::Company));
-
-    // Applied date header with sorting triangle
-    let applied_text = row![
-        text("APPLIED").size(13),
-        text(match (sort_column, sort_order) {
-            (SortColumn::DateApplied, SortOrder::Ascending) => "▲",
-            (SortColumn::DateApplied, SortOrder::Descending) => "▼",
-            _ => "⋮" // Neutral state
-        }).size(9)
-    ]
-        .spacing(5);
+        .width(Length::FillPortion(1))
+        .into()
+}
 
-    // Use button instead of container for interactivity
-    let applied_header = button(applied_text)
-        .width(Length::FillPortion(2))
-        .style(|_theme, _status| button::Style {
-            text_color: kraken_secondary_text(),
-            background: None,
-            border: Border::default(),
-            shadow: Shadow::default(),
+// Badge-sized deadline names
+fn short_kind(kind: DeadlineKind) -> &'static str {
+    match kind {
+        DeadlineKind::ApplicationClose => "Closes",
+        DeadlineKind::OfferExpiration => "Offer",
+        DeadlineKind::Other => "Due",
+    }
+}
+
+fn info_chip<'a>(label: String) -> Element<'a, Message, Theme> {
+    container(text(label).size(11))
+        .padding(Padding::from([2, 8]))
+        .style(|_| container::Style {
+            background: Some(Background::Color(kraken_highlight_subtle())),
+            text_color: Some(kraken_secondary_text()),
+            border: Border {
+                radius: 10.0.into(),
+                ..Border::default()
+            },
+            ..container::Style::default()
+        })
+        .into()
+}
+
+// Text with search matches picked out in the accent color
+fn highlighted_text<'a>(
+    content: &'a str,
+    ranges: &[Range<usize>],
+    color: Color,
+) -> text::Rich<'a, Message, Theme> {
+    let mut spans = Vec::with_capacity(ranges.len() * 2 + 1);
+    let mut last = 0;
+
+    for range in ranges {
+        if range.start > last {
+            spans.push(span(&content[last..range.start]).color(color));
+        }
+        spans.push(
+            span(&content[range.start..range.end])
+                .color(kraken_highlight_hover())
+                .background(kraken_highlight_subtle()),
+        );
+        last = range.end;
+    }
+    if last < content.len() {
+        spans.push(span(&content[last..]).color(color));
+    }
+
+    rich_text(spans)
+}
+
+// Which resume and cover letter versions went out with this application
+fn sent_documents<'a>(state: &'a JobTracker, job: &'a JobApplication) -> Element<'a, Message, Theme> {
+    [(DocumentKind::Resume, &job.resume), (DocumentKind::CoverLetter, &job.cover_letter)]
+        .into_iter()
+        .filter_map(|(kind, id)| Some((kind, state.document(id.as_deref()?)?)))
+        .fold(row![].spacing(12).align_y(Alignment::Center), |row, (kind, doc)| {
+            row.push(
+                text(format!("{}:", kind))
+                    .size(12)
+                    .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+            )
+            .push(
+                button(text(&doc.name).size(12))
+                    .style(link_button_style)
+                    .padding(Padding::from([2, 0]))
+                    .on_press(Message::OpenDocument(doc.id.clone())),
+            )
+        })
+        .into()
+}
+
+// Alternate postings kept from merged duplicates
+fn other_urls(job: &JobApplication) -> Element<'_, Message, Theme> {
+    job.other_urls
+        .iter()
+        .fold(column![].spacing(2), |col, url| {
+            col.push(
+                button(text(url).size(12))
+                    .style(link_button_style)
+                    .padding(Padding::from([2, 0]))
+                    .on_press(Message::OpenUrl(url.clone())),
+            )
+        })
+        .into()
+}
+
+fn status_history(job: &JobApplication) -> Element<'_, Message, Theme> {
+    if job.history.is_empty() && job.closure_reason.is_none() {
+        return column![].into();
+    }
+
+    let closure = job.closure_reason.as_ref().map(|reason| {
+        let mut line = format!("Closed: {} (reached {})", reason.category, reason.stage_reached);
+        if !reason.details.is_empty() {
+            line.push_str(&format!(" — {}", reason.details));
+        }
+        line
+    });
+
+    let header = column![text("History").size(13).style(|_| text::Style {
+        color: Some(kraken_secondary_text())
+    })]
+    .push_maybe(closure.map(|line| {
+        text(line).size(12).style(|_| text::Style {
+            color: Some(kraken_negative()),
         })
-        .on_press(Message::SortBy(SortColumn::DateApplied));
+    }))
+    .spacing(4);
+
+    job.history
+        .iter()
+        .fold(
+            header,
+            |col, change| {
+                let mut line = match &change.from {
+                    Some(from) => format!("{}  {} → {}", change.at, from, change.to),
+                    None => format!("{}  Added as {}", change.at, change.to),
+                };
+                if !change.note.is_empty() {
+                    line.push_str(&format!(" — {}", change.note));
+                }
+                let forced = change.forced;
+
+                col.push(
+                    row![
+                        text(line).size(12).style(|_| text::Style {
+                            color: Some(kraken_secondary_text())
+                        }),
+                        text(if forced { "forced" } else { "" })
+                            .size(11)
+                            .style(|_| text::Style { color: Some(kraken_warning()) }),
+                    ]
+                    .spacing(8),
+                )
+            },
+        )
+        .into()
+}
+
+// Scrollable plain-text view of the saved posting, with search matches highlighted
+fn description_reader<'a>(
+    state: &'a JobTracker,
+    index: usize,
+    job: &'a JobApplication,
+    ranges: &[Range<usize>],
+) -> Element<'a, Message, Theme> {
+    container(
+        column![
+            text("JOB DESCRIPTION").size(12).style(|_| text::Style {
+                color: Some(kraken_secondary_text())
+            }),
+            scrollable(
+                highlighted_text(&job.description, ranges, kraken_text())
+                    .size(14)
+                    .width(Length::Fill),
+            )
+            .height(Length::Fixed(320.0)),
+            keyword_section(state, index),
+        ]
+        .spacing(8),
+    )
+    .width(Length::Fill)
+    .padding(Padding::from([12, 18]))
+    .style(notes_panel_style)
+    .into()
+}
 
+fn notes_markdown_style() -> markdown::Style {
+    let mut style = markdown::Style::from_palette(Theme::Dark.palette());
+    style.link_color = kraken_highlight();
+    style.inline_code_color = kraken_text();
+    style
+}
+
+fn notes_panel_style(_theme: &Theme) -> container::Style {
+    container::Style {
+        background: Some(Background::Color(kraken_background())),
+        text_color: Some(kraken_text()),
+        border: Border {
+            color: kraken_border(),
+            width: 1.0,
+            radius: 6.0.into(),
+        },
+        shadow: Shadow::default(),
+    }
+}
+
+pub fn table_header(sort_keys: &[SortKey]) -> container::Container<'static, Message, Theme> {
     container(
         row![
-            company_header,
-            text("POSITION").size(13).width(Length::FillPortion(3))
-                .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
-            applied_header,
-            text("STATUS").size(13).width(Length::FillPortion(1))
-                .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
-            text("NOTES").size(13).width(Length::FillPortion(4))
+            sort_header("COMPANY", SortColumn::Company, sort_keys, 2),
+            sort_header("POSITION", SortColumn::Position, sort_keys, 3),
+            sort_header("APPLIED", SortColumn::DateApplied, sort_keys, 2),
+            sort_header("STATUS", SortColumn::Status, sort_keys, 1),
+            sort_header("UPDATED", SortColumn::LastUpdated, sort_keys, 1),
+            sort_header("PRIORITY", SortColumn::Priority, sort_keys, 1),
+            sort_header("DEADLINE", SortColumn::Deadline, sort_keys, 1),
+            text("NOTES").size(13).width(Length::FillPortion(2))
                 .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
             text("ACTIONS").size(13).width(Length::FillPortion(1))
                 .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
@@ -167,3 +493,38 @@ pub fn table_header(sort_column: SortColumn, sort_order: SortOrder) -> container
         .width(Length::Fill)
         .style(table_header_style)
 }
+
+// Clickable column header showing its sort direction, numbered when several keys are active
+fn sort_header(
+    label: &'static str,
+    column: SortColumn,
+    sort_keys: &[SortKey],
+    portion: u16,
+) -> Element<'static, Message, Theme> {
+    let indicator = match sort_keys.iter().position(|key| key.column == column) {
+        Some(i) => {
+            let arrow = match sort_keys[i].order {
+                SortOrder::Ascending => "▲",
+                SortOrder::Descending => "▼",
+            };
+            if sort_keys.len() > 1 {
+                format!("{}{}", arrow, i + 1)
+            } else {
+                arrow.to_string()
+            }
+        }
+        None => "⋮".to_string(), // Neutral state
+    };
+
+    // Use button instead of container for interactivity
+    button(row![text(label).size(13), text(indicator).size(9)].spacing(5))
+        .width(Length::FillPortion(portion))
+        .style(|_theme, _status| button::Style {
+            text_color: kraken_secondary_text(),
+            background: None,
+            border: Border::default(),
+            shadow: Shadow::default(),
+        })
+        .on_press(Message::SortBy(column))
+        .into()
+}
@@ -0,0 +1,93 @@
+use iced::{
+    widget::{button, column, container, pick_list, row, text, Space},
+    Alignment, Background, Border, Color, Element, Length, Padding, Theme,
+};
+
+use crate::documents::DocumentKind;
+use crate::message::Message;
+use crate::state::JobTracker;
+use crate::theme::*;
+use crate::ui::common::*;
+
+// Resume picker and matched/missing keywords for the open job description
+pub fn keyword_section(state: &JobTracker, index: usize) -> Element<'_, Message, Theme> {
+    let resumes = state.documents_of(DocumentKind::Resume);
+    if resumes.is_empty() {
+        return text("Add a resume in Documents to compare it against this posting")
+            .size(12)
+            .style(|_| text::Style { color: Some(kraken_secondary_text()) })
+            .into();
+    }
+
+    let selected = state
+        .keyword_resume
+        .as_deref()
+        .and_then(|id| state.document(id))
+        .cloned();
+    let can_analyze = selected.is_some() && !state.analyzing_keywords;
+
+    let controls = row![
+        text("KEYWORD MATCH")
+            .size(12)
+            .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+        Space::with_width(Length::Fill),
+        pick_list(resumes, selected, |doc| Message::KeywordResumePicked(doc.id))
+            .placeholder("Choose a resume")
+            .padding(6),
+        button(text(if state.analyzing_keywords { "Comparing..." } else { "Compare" }).size(12))
+            .style(secondary_button_style)
+            .padding(Padding::from([6, 12]))
+            .on_press_maybe(can_analyze.then_some(Message::AnalyzeKeywords(index))),
+    ]
+    .spacing(10)
+    .align_y(Alignment::Center);
+
+    let Some((_, report)) = state.keyword_report.as_ref().filter(|(i, _)| *i == index) else {
+        return controls.into();
+    };
+
+    column![
+        controls,
+        text(format!(
+            "{}% coverage: {} of {} keywords found in the resume",
+            report.coverage(),
+            report.matched.len(),
+            report.matched.len() + report.missing.len()
+        ))
+        .size(13)
+        .style(|_| text::Style { color: Some(kraken_text()) }),
+        keyword_chips("Matched", &report.matched, kraken_highlight()),
+        keyword_chips("Missing", &report.missing, kraken_negative()),
+    ]
+    .spacing(8)
+    .into()
+}
+
+fn keyword_chips<'a>(label: &'a str, keywords: &'a [String], color: Color) -> Element<'a, Message, Theme> {
+    keywords
+        .iter()
+        .fold(
+            row![text(label)
+                .size(12)
+                .width(Length::Fixed(60.0))
+                .style(|_| text::Style { color: Some(kraken_secondary_text()) })]
+            .spacing(6),
+            |chips, keyword| {
+                chips.push(
+                    container(text(keyword).size(12).style(move |_| text::Style { color: Some(color) }))
+                        .padding(Padding::from([2, 8]))
+                        .style(move |_| container::Style {
+                            background: Some(Background::Color(Color { a: 0.12, ..color })),
+                            border: Border {
+                                color,
+                                width: 1.0,
+                                radius: 10.0.into(),
+                            },
+                            ..container::Style::default()
+                        }),
+                )
+            },
+        )
+        .wrap()
+        .into()
+}
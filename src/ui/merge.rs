@@ -0,0 +1,139 @@
+use iced::{
+    widget::{button, column, container, row, text, Space},
+    Alignment, Background, Border, Element, Length, Padding, Theme,
+};
+
+use crate::data::JobApplication;
+use crate::duplicates::MergeField;
+use crate::message::Message;
+use crate::state::{JobTracker, MergeState};
+use crate::theme::*;
+use crate::ui::common::*;
+
+// Likely duplicate pairs from the background scan, each with a Merge button
+pub fn duplicates_panel(state: &JobTracker) -> Element<'_, Message, Theme> {
+    let pairs = state.current_duplicates();
+
+    let rows = pairs.into_iter().fold(column![].spacing(8), |col, (a, b, reason)| {
+        col.push(
+            row![
+                text(summary(&state.jobs[a]))
+                    .size(13)
+                    .width(Length::FillPortion(3))
+                    .style(|_| text::Style { color: Some(kraken_text()) }),
+                text("⟷").size(13).style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+                text(summary(&state.jobs[b]))
+                    .size(13)
+                    .width(Length::FillPortion(3))
+                    .style(|_| text::Style { color: Some(kraken_text()) }),
+                text(reason.to_string())
+                    .size(12)
+                    .width(Length::FillPortion(2))
+                    .style(|_| text::Style { color: Some(kraken_warning()) }),
+                button(text("Merge").size(12))
+                    .style(secondary_button_style)
+                    .padding(Padding::from([4, 10]))
+                    .on_press(Message::StartMerge(a, b)),
+            ]
+            .spacing(10)
+            .align_y(Alignment::Center),
+        )
+    });
+
+    container(
+        column![
+            text("POSSIBLE DUPLICATES")
+                .size(12)
+                .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+            rows,
+        ]
+        .spacing(10),
+    )
+    .width(Length::Fill)
+    .padding(Padding::from([12, 15]))
+    .style(|_| container::Style {
+        background: Some(Background::Color(kraken_card_bg())),
+        text_color: Some(kraken_text()),
+        border: Border {
+            color: kraken_warning(),
+            width: 1.0,
+            radius: 8.0.into(),
+        },
+        ..container::Style::default()
+    })
+    .into()
+}
+
+// Side-by-side comparison where each field is picked from one of the two records
+pub fn merge_screen<'a>(state: &'a JobTracker, merge: &'a MergeState) -> container::Container<'a, Message, Theme> {
+    let (Some(left), Some(right)) = (state.jobs.get(merge.left), state.jobs.get(merge.right)) else {
+        return container(Space::with_height(0)).width(Length::Fill);
+    };
+
+    let field_rows = MergeField::ALL.into_iter().fold(column![].spacing(8), |col, field| {
+        let take_right = merge.take_right.contains(&field);
+        col.push(
+            row![
+                text(field.label())
+                    .size(12)
+                    .width(Length::Fixed(100.0))
+                    .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+                choice(field.value(left), !take_right, Message::MergeFieldPicked(field, false)),
+                choice(field.value(right), take_right, Message::MergeFieldPicked(field, true)),
+            ]
+            .spacing(10)
+            .align_y(Alignment::Center),
+        )
+    });
+
+    let history_count = left.history.len() + right.history.len();
+
+    let content = column![
+        text("Merge Applications")
+            .size(18)
+            .style(|_| text::Style { color: Some(kraken_text()) }),
+        text("Pick which value to keep for each field. Notes, status history and URLs from both are combined, and the second record is removed.")
+            .size(12)
+            .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+        field_rows,
+        text(format!(
+            "{} status history entries will be combined.",
+            history_count
+        ))
+        .size(12)
+        .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+        row![
+            Space::with_width(Length::Fill),
+            button(text("Cancel").size(14))
+                .style(secondary_button_style)
+                .padding(Padding::from([8, 15]))
+                .on_press(Message::CancelMerge),
+            button(text("Merge").size(14))
+                .style(primary_button_style)
+                .padding(Padding::from([8, 15]))
+                .on_press(Message::ConfirmMerge),
+        ]
+        .spacing(10),
+    ]
+    .spacing(15)
+    .padding(20);
+
+    container(content)
+        .width(Length::Fill)
+        .style(panel_style)
+}
+
+fn summary(job: &JobApplication) -> String {
+    format!("{} — {} ({})", job.company, job.position, job.date_applied)
+}
+
+// One side's value for a field, highlighted when it is the one being kept
+fn choice(value: &str, selected: bool, message: Message) -> Element<'_, Message, Theme> {
+    let label = if value.is_empty() { "(empty)" } else { value };
+    button(text(label).size(13))
+        .width(Length::FillPortion(1))
+        .padding(Padding::from([6, 10]))
+        .style(move |theme, status| toggle_chip_style(theme, status, selected))
+        .on_press(message)
+        .into()
+}
@@ -1,23 +1,48 @@
+pub mod activity;
+pub mod calendar;
 pub mod common;
+pub mod company;
+pub mod documents;
 pub mod form;
+pub mod goals;
 pub mod header;
 pub mod job_row;
+pub mod keywords;
+pub mod merge;
+pub mod pipeline;
+pub mod stats;
+pub mod sync;
+pub mod templates;
+pub mod trash;
 
 use iced::{
     alignment,
     widget::{button, column, container, horizontal_rule, row, scrollable, text, Space},
-    Element, Length, Padding, Theme,
+    Background, Border, Element, Length, Padding, Theme,
 };
 
+use crate::calendar::ViewMode;
+use crate::data::{GroupBy, JobApplication};
 use crate::message::Message;
-use crate::state::JobTracker; // Removed unused imports
+use crate::state::{JobTracker, ListScope}; // Removed unused imports
 use crate::theme::*;
+use crate::ui::activity::activity_panel;
+use crate::ui::calendar::calendar_view;
 use crate::ui::common::*;
+use crate::ui::company::{companies_panel, company_page};
+use crate::ui::documents::documents_panel;
 use crate::ui::form::{add_form, edit_form};
+use crate::ui::goals::goals_panel;
 use crate::ui::header::app_header;
 use crate::ui::job_row::{job_row, table_header};
+use crate::ui::merge::{duplicates_panel, merge_screen};
+use crate::ui::pipeline::pipeline_editor;
+use crate::ui::stats::{reason_breakdown, source_panel, timing_panel};
+use crate::ui::sync::conflict_screen;
+use crate::ui::templates::templates_panel;
+use crate::ui::trash::{archive_banner, trash_view};
 
-pub fn view(state: &JobTracker) -> Element<'_, Message, Theme> {
+pub fn view<'a>(state: &'a JobTracker) -> Element<'a, Message, Theme> {
     // App header with upgraded Kraken-style
     let header = app_header(state);
 
@@ -25,52 +50,128 @@ pub fn view(state: &JobTracker) -> Element<'_, Message, Theme> {
     let jobs_to_display = state.sorted_jobs();
 
     // Create job rows with enhanced styling (with inline edit form)
-    let job_rows = jobs_to_display
-        .iter()
-        .fold(column![].spacing(12), |col, &(index, job)| {
-            // If this index is being edited, show the edit form directly in place of that row
-            if state.editing_index == Some(index) {
-                col.push(edit_form(index, &state.edit_form))
-            } else {
-                col.push(job_row(index, job))
-            }
+    let add_row = |col: iced::widget::Column<'a, Message, Theme>, &(index, job): &(usize, &'a JobApplication)| {
+        // If this index is being edited, show the edit form directly in place of that row
+        if state.editing_index == Some(index) {
+            col.push(edit_form(index, state))
+        } else {
+            col.push(job_row(state, index, job))
+        }
+    };
+    let job_rows = if state.group_by == GroupBy::None {
+        jobs_to_display.iter().fold(column![].spacing(12), add_row)
+    } else {
+        // A heading with the count above each group's rows
+        state.grouped_jobs().into_iter().fold(column![].spacing(12), |col, (label, jobs)| {
+            let heading = text(format!("{} ({})", label, jobs.len()))
+                .size(13)
+                .style(|_| text::Style {
+                    color: Some(kraken_highlight())
+                });
+            jobs.iter().fold(col.push(container(heading).padding(Padding::from([8, 5]).bottom(0))), add_row)
+        })
+    };
+
+    // Per-stage counts in pipeline order, skipping empty stages
+    let stage_counts = state.pipeline.stages.iter().fold(row![].spacing(15), |counts, stage| {
+        let count = state.jobs.iter().filter(|j| j.status == stage.name).count();
+        if count == 0 {
+            return counts;
+        }
+        let color = stage.color();
+        counts.push(
+            row![
+                container(Space::new(Length::Fixed(8.0), Length::Fixed(8.0))).style(move |_| {
+                    container::Style {
+                        background: Some(Background::Color(color)),
+                        border: Border {
+                            radius: 4.0.into(),
+                            ..Border::default()
+                        },
+                        ..container::Style::default()
+                    }
+                }),
+                text(format!("{}: {}", stage.name, count))
+                    .size(12)
+                    .style(|_| text::Style {
+                        color: Some(kraken_secondary_text())
+                    }),
+            ]
+            .spacing(5)
+            .align_y(alignment::Vertical::Center),
+        )
+    });
+
+    let duplicate_count = state.current_duplicates().len();
+
+    // List/calendar switch, then the statistics summary
+    let view_tabs = [(ViewMode::List, "List"), (ViewMode::Calendar, "Calendar")]
+        .into_iter()
+        .fold(row![].spacing(6), |tabs, (mode, label)| {
+            tabs.push(
+                button(text(label).size(12))
+                    .style(move |theme, status| toggle_chip_style(theme, status, state.view_mode == mode))
+                    .padding(Padding::from([4, 10]))
+                    .on_press(Message::SetViewMode(mode)),
+            )
         });
 
-    // Status summary counts
-    let applied_count = state
-        .jobs
-        .iter()
-        .filter(|j| j.status.to_string() == "Applied")
-        .count();
-    let rejected_count = state
-        .jobs
-        .iter()
-        .filter(|j| j.status.to_string() == "Rejected")
-        .count();
-    let offer_count = state
-        .jobs
-        .iter()
-        .filter(|j| j.status.to_string() == "Offer")
-        .count();
+    // Archive and trash chips switch the list to those applications; clicking again returns to it
+    let archived = state.jobs.iter().filter(|job| job.archived).count();
+    let scope_tabs = [
+        (ListScope::Archive, format!("Archive ({})", archived)),
+        (ListScope::Trash, format!("Trash ({})", state.trash.len())),
+    ]
+    .into_iter()
+    .fold(row![].spacing(6), |tabs, (scope, label)| {
+        let active = state.list_scope == scope;
+        tabs.push(
+            button(text(label).size(12))
+                .style(move |theme, status| toggle_chip_style(theme, status, active))
+                .padding(Padding::from([4, 10]))
+                .on_press(Message::SetListScope(if active { ListScope::Applications } else { scope })),
+        )
+    });
 
-    // Statistics summary
     let stats_row = row![
-        text(format!("Applied: {}", applied_count))
-            .size(12)
-            .style(|_| text::Style {
-                color: Some(kraken_secondary_text())
-            }),
-        text(format!("Rejected: {}", rejected_count))
-            .size(12)
-            .style(|_| text::Style {
-                color: Some(kraken_secondary_text())
-            }),
-        text(format!("Offers: {}", offer_count))
-            .size(12)
-            .style(|_| text::Style {
-                color: Some(kraken_secondary_text())
-            }),
+        view_tabs,
+        scope_tabs,
+        stage_counts,
         Space::with_width(Length::Fill),
+    ]
+    .push_maybe(state.day_filter.map(|day| {
+        button(text(format!("On {} ✕", day.format("%Y-%m-%d"))).size(12))
+            .style(|theme, status| toggle_chip_style(theme, status, true))
+            .padding(Padding::from([4, 10]))
+            .on_press(Message::ClearDayFilter)
+    }))
+    .push_maybe((duplicate_count > 0).then(|| {
+        button(text(format!("Possible duplicates: {}", duplicate_count)).size(12))
+            .style(|theme, status| toggle_chip_style(theme, status, state.show_duplicates))
+            .padding(Padding::from([4, 10]))
+            .on_press(Message::ToggleDuplicates)
+    }))
+    .extend([
+        button(text("Activity").size(12))
+            .style(|theme, status| toggle_chip_style(theme, status, state.show_activity))
+            .padding(Padding::from([4, 10]))
+            .on_press(Message::ToggleActivity)
+            .into(),
+        button(text("Sources").size(12))
+            .style(|theme, status| toggle_chip_style(theme, status, state.show_sources))
+            .padding(Padding::from([4, 10]))
+            .on_press(Message::ToggleSources)
+            .into(),
+        button(text("Timing").size(12))
+            .style(|theme, status| toggle_chip_style(theme, status, state.show_timing))
+            .padding(Padding::from([4, 10]))
+            .on_press(Message::ToggleTiming)
+            .into(),
+        button(text("Closure reasons").size(12))
+            .style(|theme, status| toggle_chip_style(theme, status, state.show_reason_stats))
+            .padding(Padding::from([4, 10]))
+            .on_press(Message::ToggleReasonStats)
+            .into(),
         text(format!(
             "Showing {} of {} applications",
             jobs_to_display.len(),
@@ -79,13 +180,18 @@ pub fn view(state: &JobTracker) -> Element<'_, Message, Theme> {
         .size(12)
         .style(|_| text::Style {
             color: Some(kraken_secondary_text())
-        }),
-    ]
+        })
+        .into(),
+    ])
         .spacing(15)
         .padding(Padding::new(10.0));
 
     // Empty state message when no jobs match filters
-    let jobs_content = if jobs_to_display.is_empty() && !state.jobs.is_empty() {
+    let jobs_content = if state.list_scope == ListScope::Trash {
+        column![trash_view(state)]
+    } else if state.view_mode == ViewMode::Calendar {
+        column![calendar_view(state)]
+    } else if jobs_to_display.is_empty() && !state.jobs.is_empty() {
         column![
             Space::with_height(Length::Fixed(30.0)),
             text("No applications match your filters")
@@ -104,7 +210,7 @@ pub fn view(state: &JobTracker) -> Element<'_, Message, Theme> {
             .width(Length::Fill)
     } else {
         column![
-            table_header(state.sort_column, state.sort_order),
+            table_header(&state.sort_keys),
             job_rows,
             // Add some space at the bottom
             Space::with_height(Length::Fixed(20.0)),
@@ -123,13 +229,68 @@ pub fn view(state: &JobTracker) -> Element<'_, Message, Theme> {
                 fill_mode: iced::widget::rule::FillMode::Full,
             }
         }),
-        // Only show add form if we're not currently editing
-        if state.editing_index.is_none() {
+        // Sync conflicts, the merge screen and library/template/goal/registry/pipeline panels take the place of the add form while open
+        if let Some(sync) = &state.sync {
+            container(conflict_screen(sync)).padding(Padding::from([10, 20]))
+        } else if let Some(merge) = &state.merge {
+            container(merge_screen(state, merge)).padding(Padding::from([10, 20]))
+        } else if state.show_documents {
+            container(documents_panel(state)).padding(Padding::from([10, 20]))
+        } else if state.show_templates {
+            container(templates_panel(state)).padding(Padding::from([10, 20]))
+        } else if state.show_goals {
+            container(goals_panel(state)).padding(Padding::from([10, 20]))
+        } else if state.show_companies {
+            container(companies_panel(state)).padding(Padding::from([10, 20]))
+        } else if state.show_pipeline_editor {
+            container(pipeline_editor(state)).padding(Padding::from([10, 20]))
+        } else if state.editing_index.is_none() && state.open_company.is_none() {
             add_form(state)
         } else {
             container(Space::with_height(Length::Fixed(0.0))).width(Length::Fill)
         },
-        container(column![stats_row, jobs_content,].spacing(15))
+        // A company page replaces the full application list
+        if let Some(view) = &state.open_company {
+            container(company_page(state, view))
+        } else {
+            container(
+                column![
+                    stats_row,
+                    if state.list_scope == ListScope::Archive {
+                        archive_banner(state)
+                    } else {
+                        Space::with_height(0).into()
+                    },
+                    if state.show_duplicates && duplicate_count > 0 {
+                        duplicates_panel(state)
+                    } else {
+                        Space::with_height(0).into()
+                    },
+                    if state.show_activity {
+                        activity_panel(state)
+                    } else {
+                        Space::with_height(0).into()
+                    },
+                    if state.show_sources {
+                        source_panel(state)
+                    } else {
+                        Space::with_height(0).into()
+                    },
+                    if state.show_timing {
+                        timing_panel(state)
+                    } else {
+                        Space::with_height(0).into()
+                    },
+                    if state.show_reason_stats {
+                        reason_breakdown(state)
+                    } else {
+                        Space::with_height(0).into()
+                    },
+                    jobs_content,
+                ]
+                .spacing(15)
+            )
+        }
             .padding(Padding::new(20.0))
             .width(Length::Fill)
     ];
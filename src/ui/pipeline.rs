@@ -0,0 +1,192 @@
+use iced::{
+    widget::{button, checkbox, column, container, pick_list, row, text, text_input, Space},
+    Alignment, Background, Border, Element, Length, Padding, Theme,
+};
+
+use crate::message::Message;
+use crate::pipeline::{PipelinePreset, RequiredField, Stage, StageOutcome};
+use crate::state::JobTracker;
+use crate::theme::*;
+use crate::ui::common::*;
+
+pub fn pipeline_editor(state: &JobTracker) -> container::Container<'_, Message, Theme> {
+    let stage_count = state.pipeline.stages.len();
+
+    let stage_rows = state
+        .pipeline
+        .stages
+        .iter()
+        .enumerate()
+        .fold(column![].spacing(8), |col, (index, stage)| {
+            let in_use = state.jobs.iter().filter(|job| job.status == stage.name).count();
+            let name = match &state.stage_rename {
+                Some((editing, name)) if *editing == index => name.as_str(),
+                _ => stage.name.as_str(),
+            };
+            let col = col.push(stage_row(index, stage, name, in_use, stage_count));
+            if state.editing_rules_for == Some(index) {
+                col.push(rules_panel(state, index, stage))
+            } else {
+                col
+            }
+        });
+
+    let content = column![
+        row![
+            text("Pipeline Stages")
+                .size(18)
+                .style(|_| text::Style { color: Some(kraken_text()) }),
+            Space::with_width(Length::Fill),
+            text("Start from:")
+                .size(12)
+                .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+            pick_list(PipelinePreset::ALL, None::<PipelinePreset>, Message::PipelinePresetSelected)
+                .placeholder("Preset")
+                .padding(6),
+        ]
+        .spacing(10)
+        .align_y(Alignment::Center),
+        text("Stages are listed in pipeline order. Terminal stages count as closed, interview stages as interviews in the reports. Press Enter to apply a new name.")
+            .size(12)
+            .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+        stage_rows,
+        row![
+            button(text("Add Stage").size(14))
+                .style(secondary_button_style)
+                .padding(Padding::from([8, 15]))
+                .on_press(Message::AddStage),
+            Space::with_width(Length::Fill),
+            button(text("Done").size(14))
+                .style(primary_button_style)
+                .padding(Padding::from([8, 15]))
+                .on_press(Message::TogglePipelineEditor),
+        ]
+        .spacing(10),
+    ]
+    .spacing(15)
+    .padding(20);
+
+    container(content)
+        .width(Length::Fill)
+        .style(panel_style)
+}
+
+fn stage_row<'a>(index: usize, stage: &'a Stage, name: &'a str, in_use: usize, stage_count: usize) -> Element<'a, Message, Theme> {
+    let swatch_color = stage.color();
+
+    row![
+        container(Space::new(Length::Fixed(14.0), Length::Fixed(14.0))).style(move |_| container::Style {
+            background: Some(Background::Color(swatch_color)),
+            border: Border {
+                color: kraken_border(),
+                width: 1.0,
+                radius: 3.0.into(),
+            },
+            ..container::Style::default()
+        }),
+        text_input("Stage name", name)
+            .padding(6)
+            .width(Length::FillPortion(3))
+            .style(input_style)
+            .on_input(move |name| Message::StageNameChanged(index, name))
+            .on_submit(Message::StageNameSubmitted),
+        text_input("#rrggbb", &stage.color)
+            .padding(6)
+            .width(Length::Fixed(90.0))
+            .style(input_style)
+            .on_input(move |color| Message::StageColorChanged(index, color)),
+        pick_list(StageOutcome::ALL, Some(stage.outcome), move |outcome| {
+            Message::StageOutcomeSelected(index, outcome)
+        })
+        .padding(6)
+        .width(Length::FillPortion(2)),
+        checkbox("Terminal", stage.terminal)
+            .on_toggle(move |terminal| Message::StageTerminalToggled(index, terminal))
+            .size(16)
+            .text_size(13),
+        checkbox("Interview", stage.is_interview())
+            .on_toggle(move |interview| Message::StageInterviewToggled(index, interview))
+            .size(16)
+            .text_size(13),
+        text(format!("{} jobs", in_use))
+            .size(12)
+            .width(Length::Fixed(55.0))
+            .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+        button(text("Rules").size(11))
+            .style(secondary_button_style)
+            .padding(Padding::from([4, 8]))
+            .on_press(Message::ToggleStageRules(index)),
+        button(text("▲").size(11))
+            .style(secondary_button_style)
+            .padding(Padding::from([4, 8]))
+            .on_press_maybe((index > 0).then_some(Message::MoveStage(index, true))),
+        button(text("▼").size(11))
+            .style(secondary_button_style)
+            .padding(Padding::from([4, 8]))
+            .on_press_maybe((index + 1 < stage_count).then_some(Message::MoveStage(index, false))),
+        button(text("✕").size(11))
+            .style(delete_button_style)
+            .padding(Padding::from([4, 8]))
+            .on_press(Message::RemoveStage(index)),
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center)
+    .into()
+}
+
+// Allowed next stages and required fields for one stage
+fn rules_panel<'a>(state: &'a JobTracker, index: usize, stage: &'a Stage) -> Element<'a, Message, Theme> {
+    let any_allowed = stage.allowed_next.is_none();
+
+    let next_stages = state
+        .pipeline
+        .stages
+        .iter()
+        .filter(|next| next.name != stage.name)
+        .fold(row![].spacing(6), |chips, next| {
+            chips.push(
+                checkbox(next.name.clone(), stage.allows(&next.name))
+                    .on_toggle_maybe((!any_allowed).then_some({
+                        let name = next.name.clone();
+                        move |_| Message::StageTransitionToggled(index, name.clone())
+                    }))
+                    .size(14)
+                    .text_size(12),
+            )
+        })
+        .wrap();
+
+    let requirements = RequiredField::ALL.into_iter().fold(row![].spacing(12), |fields, field| {
+        fields.push(
+            checkbox(field.to_string(), stage.requires.contains(&field))
+                .on_toggle(move |required| Message::StageRequirementToggled(index, field, required))
+                .size(14)
+                .text_size(12),
+        )
+    });
+
+    container(
+        column![
+            row![
+                text(format!("From \"{}\" an application may move to:", stage.name))
+                    .size(12)
+                    .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+                Space::with_width(Length::Fill),
+                checkbox("Any stage", any_allowed)
+                    .on_toggle(move |any| Message::StageAnyTransitionToggled(index, any))
+                    .size(14)
+                    .text_size(12),
+            ]
+            .align_y(Alignment::Center),
+            next_stages,
+            text("Required when entering this stage:")
+                .size(12)
+                .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+            requirements,
+        ]
+        .spacing(8),
+    )
+    .padding(Padding::from([10, 30]))
+    .width(Length::Fill)
+    .into()
+}
@@ -0,0 +1,286 @@
+use std::collections::HashMap;
+
+use iced::{
+    widget::{button, column, container, row, text, text_input, Space},
+    Alignment, Background, Border, Color, Element, Length, Padding, Theme,
+};
+
+use crate::data::ReasonCategory;
+use crate::message::Message;
+use crate::state::JobTracker;
+use crate::metrics;
+use crate::theme::*;
+use crate::ui::common::{input_style, secondary_button_style, toggle_chip_style};
+
+// Breakdown of why rejected and withdrawn applications ended, and how far they got
+pub fn reason_breakdown(state: &JobTracker) -> Element<'_, Message, Theme> {
+    let reasons: Vec<_> = state
+        .jobs
+        .iter()
+        .filter_map(|job| job.closure_reason.as_ref())
+        .collect();
+    let unexplained = state
+        .jobs
+        .iter()
+        .filter(|job| state.pipeline.outcome(&job.status).is_negative() && job.closure_reason.is_none())
+        .count();
+
+    if reasons.is_empty() {
+        return panel(
+            column![muted(format!(
+                "No closure reasons recorded yet ({} closed without one)",
+                unexplained
+            ))]
+            .into(),
+        );
+    }
+
+    let mut by_category: HashMap<ReasonCategory, usize> = HashMap::new();
+    let mut by_stage: HashMap<&str, usize> = HashMap::new();
+    for reason in &reasons {
+        *by_category.entry(reason.category).or_default() += 1;
+        *by_stage.entry(reason.stage_reached.as_str()).or_default() += 1;
+    }
+
+    let category_bars: Vec<(String, usize, Color)> = ReasonCategory::ALL
+        .iter()
+        .filter_map(|category| {
+            by_category
+                .get(category)
+                .map(|&count| (category.to_string(), count, kraken_negative()))
+        })
+        .collect();
+
+    // Stages in pipeline order, with any stage no longer in the pipeline at the end
+    let mut stage_bars: Vec<(String, usize, Color)> = state
+        .pipeline
+        .stages
+        .iter()
+        .filter_map(|stage| {
+            by_stage
+                .get(stage.name.as_str())
+                .map(|&count| (stage.name.clone(), count, stage.color()))
+        })
+        .collect();
+    for (name, count) in &by_stage {
+        if state.pipeline.stage(name).is_none() {
+            stage_bars.push((name.to_string(), *count, Color::from_rgb(0.5, 0.5, 0.5)));
+        }
+    }
+
+    let mut content = row![
+        bar_chart("BY REASON", category_bars),
+        bar_chart("BY STAGE REACHED", stage_bars),
+    ]
+    .spacing(30);
+
+    if unexplained > 0 {
+        content = content.push(muted(format!("{} closed without a reason", unexplained)));
+    }
+
+    panel(content.into())
+}
+
+// How long companies take to respond to applications and to decide after interviews
+pub fn timing_panel(state: &JobTracker) -> Element<'_, Message, Theme> {
+    let (overall, companies) = metrics::timing_report(&state.jobs, &state.pipeline);
+    if overall.response.is_none() && overall.decision.is_none() {
+        return panel(
+            column![muted(
+                "No timing data yet: response times come from status changes recorded after applying".to_string()
+            )]
+            .into(),
+        );
+    }
+
+    let header = ["", "RESPONSES", "MEDIAN", "P90", "DECISIONS", "MEDIAN", "P90"]
+        .into_iter()
+        .enumerate()
+        .fold(row![].spacing(10), |header, (i, title)| {
+            header.push(
+                text(title)
+                    .size(11)
+                    .width(if i == 0 { Length::FillPortion(3) } else { Length::FillPortion(1) })
+                    .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+            )
+        });
+
+    let rows = std::iter::once(("All applications".to_string(), overall))
+        .chain(companies)
+        .fold(column![header].spacing(6), |rows, (name, timing)| {
+            rows.push(
+                row![text(name).size(12).width(Length::FillPortion(3))]
+                    .extend(summary_cells(timing.response))
+                    .extend(summary_cells(timing.decision))
+                    .spacing(10),
+            )
+        });
+
+    panel(
+        column![
+            text("TIME TO FIRST RESPONSE AND FROM INTERVIEW TO DECISION (DAYS)")
+                .size(12)
+                .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+            rows,
+        ]
+        .spacing(10)
+        .into(),
+    )
+}
+
+// Conversion by application source, with the list of sources offered in the forms
+pub fn source_panel(state: &JobTracker) -> Element<'_, Message, Theme> {
+    let report = metrics::source_report(&state.jobs, &state.pipeline);
+
+    let header = ["SOURCE", "APPLIED", "INTERVIEWS", "OFFERS", "TO INTERVIEW", "TO OFFER"]
+        .into_iter()
+        .enumerate()
+        .fold(row![].spacing(10), |header, (i, title)| {
+            header.push(
+                text(title)
+                    .size(11)
+                    .width(if i == 0 { Length::FillPortion(3) } else { Length::FillPortion(1) })
+                    .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+            )
+        });
+
+    let table = report.into_iter().fold(column![header].spacing(6), |rows, counts| {
+        let name = if counts.source.is_empty() { "No source".to_string() } else { counts.source.clone() };
+        rows.push(
+            row![
+                text(name).size(12).width(Length::FillPortion(3)),
+                text(counts.applied.to_string()).size(12).width(Length::FillPortion(1)),
+                text(counts.interviews.to_string()).size(12).width(Length::FillPortion(1)),
+                text(counts.offers.to_string()).size(12).width(Length::FillPortion(1)),
+                text(format!("{:.0}%", counts.interview_rate())).size(12).width(Length::FillPortion(1)),
+                text(format!("{:.0}%", counts.offer_rate())).size(12).width(Length::FillPortion(1)),
+            ]
+            .spacing(10),
+        )
+    });
+
+    let configured = state.settings.sources.iter().enumerate().fold(row![].spacing(6), |chips, (index, source)| {
+        chips.push(
+            button(text(format!("{} ✕", source)).size(11))
+                .style(|theme, status| toggle_chip_style(theme, status, false))
+                .padding(Padding::from([3, 8]))
+                .on_press(Message::RemoveSource(index)),
+        )
+    });
+
+    panel(
+        column![
+            table,
+            text("SOURCES OFFERED IN THE FORM")
+                .size(11)
+                .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+            row![
+                configured.wrap(),
+                text_input("New source", &state.new_source_input)
+                    .padding(5)
+                    .width(Length::Fixed(180.0))
+                    .style(input_style)
+                    .on_input(Message::NewSourceChanged)
+                    .on_submit(Message::AddSource),
+                button(text("Add").size(12))
+                    .style(secondary_button_style)
+                    .padding(Padding::from([5, 10]))
+                    .on_press(Message::AddSource),
+            ]
+            .spacing(10)
+            .align_y(Alignment::Center),
+        ]
+        .spacing(10)
+        .into(),
+    )
+}
+
+// Count, median and p90 columns, blank when nothing was measured
+fn summary_cells(summary: Option<metrics::Summary>) -> Vec<Element<'static, Message, Theme>> {
+    let values = match summary {
+        Some(summary) => [
+            summary.count.to_string(),
+            format!("{:.1}", summary.median),
+            summary.p90.to_string(),
+        ],
+        None => ["-".to_string(), "-".to_string(), "-".to_string()],
+    };
+
+    values
+        .into_iter()
+        .map(|value| text(value).size(12).width(Length::FillPortion(1)).into())
+        .collect()
+}
+
+fn bar_chart(title: &str, bars: Vec<(String, usize, Color)>) -> Element<'_, Message, Theme> {
+    let max = bars.iter().map(|(_, count, _)| *count).max().unwrap_or(1).max(1);
+
+    bars.into_iter()
+        .fold(
+            column![text(title).size(12).style(|_| text::Style {
+                color: Some(kraken_secondary_text())
+            })]
+            .spacing(6)
+            .width(Length::FillPortion(1)),
+            |col, (label, count, color)| {
+                col.push(
+                    row![
+                        text(label).size(12).width(Length::Fixed(150.0)),
+                        bar(count, max, color),
+                        text(count.to_string()).size(12).width(Length::Fixed(30.0)),
+                    ]
+                    .spacing(8)
+                    .align_y(Alignment::Center),
+                )
+            },
+        )
+        .into()
+}
+
+// A horizontal bar filling count/max of the available width
+fn bar(count: usize, max: usize, color: Color) -> Element<'static, Message, Theme> {
+    let filled = count.min(max) as u16;
+    let empty = (max - count.min(max)) as u16;
+
+    let mut track = row![container(Space::with_height(Length::Fixed(10.0)))
+        .width(Length::FillPortion(filled.max(1)))
+        .style(move |_| container::Style {
+            background: Some(Background::Color(color)),
+            border: Border {
+                radius: 3.0.into(),
+                ..Border::default()
+            },
+            ..container::Style::default()
+        })];
+    if empty > 0 {
+        track = track.push(Space::with_width(Length::FillPortion(empty)));
+    }
+
+    track.width(Length::Fill).into()
+}
+
+fn muted(content: String) -> Element<'static, Message, Theme> {
+    text(content)
+        .size(12)
+        .style(|_| text::Style {
+            color: Some(kraken_secondary_text()),
+        })
+        .into()
+}
+
+pub fn panel(content: Element<'_, Message, Theme>) -> Element<'_, Message, Theme> {
+    container(content)
+        .width(Length::Fill)
+        .padding(Padding::from([12, 15]))
+        .style(|_| container::Style {
+            background: Some(Background::Color(kraken_card_bg())),
+            text_color: Some(kraken_text()),
+            border: Border {
+                color: kraken_card_border(),
+                width: 1.0,
+                radius: 8.0.into(),
+            },
+            ..container::Style::default()
+        })
+        .into()
+}
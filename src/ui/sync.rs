@@ -0,0 +1,95 @@
+use iced::{
+    widget::{button, column, container, row, text, Space},
+    Alignment, Element, Length, Padding, Theme,
+};
+
+use crate::data::JobApplication;
+use crate::message::Message;
+use crate::sync::Reconciled;
+use crate::theme::*;
+use crate::ui::common::*;
+
+// Applications changed both here and in the data file, each kept from one side
+pub fn conflict_screen(sync: &Reconciled) -> container::Container<'_, Message, Theme> {
+    let conflict_rows = sync.conflicts.iter().enumerate().fold(column![].spacing(12), |col, (index, conflict)| {
+        let differences = conflict.differences();
+        col.push(
+            column![
+                text(conflict.title())
+                    .size(14)
+                    .style(|_| text::Style { color: Some(kraken_text()) }),
+            ]
+            .push_maybe((!differences.is_empty()).then(|| {
+                text(format!("Differs in: {}", differences.join(", ")))
+                    .size(12)
+                    .style(|_| text::Style { color: Some(kraken_warning()) })
+            }))
+            .push(
+                row![
+                    choice("Mine", conflict.mine.as_ref(), conflict.keep_mine, Message::ResolveConflict(index, true)),
+                    choice("File", conflict.theirs.as_ref(), !conflict.keep_mine, Message::ResolveConflict(index, false)),
+                ]
+                .spacing(10),
+            )
+            .spacing(6),
+        )
+    });
+
+    let content = column![
+        text("Data File Changed")
+            .size(18)
+            .style(|_| text::Style { color: Some(kraken_text()) }),
+        text("Another program changed the data file while these applications had changes here too. Everything else has been merged; pick which version to keep for each of these.")
+            .size(12)
+            .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+        conflict_rows,
+        row![
+            button(text("Keep All Mine").size(13))
+                .style(secondary_button_style)
+                .padding(Padding::from([8, 15]))
+                .on_press(Message::ResolveAllConflicts(true)),
+            button(text("Take All From File").size(13))
+                .style(secondary_button_style)
+                .padding(Padding::from([8, 15]))
+                .on_press(Message::ResolveAllConflicts(false)),
+            Space::with_width(Length::Fill),
+            button(text("Apply").size(14))
+                .style(primary_button_style)
+                .padding(Padding::from([8, 15]))
+                .on_press(Message::ApplyResolution),
+        ]
+        .spacing(10)
+        .align_y(Alignment::Center),
+    ]
+    .spacing(15)
+    .padding(20);
+
+    container(content)
+        .width(Length::Fill)
+        .style(conflict_panel_style)
+}
+
+// One side's version, highlighted when it is the one being kept
+fn choice<'a>(side: &str, job: Option<&JobApplication>, selected: bool, message: Message) -> Element<'a, Message, Theme> {
+    let summary = match job {
+        Some(job) => format!(
+            "{}: {} · updated {}",
+            side,
+            job.status,
+            job.last_updated.as_deref().unwrap_or("-")
+        ),
+        None => format!("{}: deleted", side),
+    };
+    button(text(summary).size(13))
+        .width(Length::FillPortion(1))
+        .padding(Padding::from([6, 10]))
+        .style(move |theme, status| toggle_chip_style(theme, status, selected))
+        .on_press(message)
+        .into()
+}
+
+fn conflict_panel_style(_theme: &Theme) -> container::Style {
+    let mut style = panel_style(_theme);
+    style.border.color = kraken_warning();
+    style
+}
@@ -0,0 +1,242 @@
+use iced::{
+    widget::{button, column, container, pick_list, row, text, text_editor, text_input, Space},
+    Alignment, Background, Border, Element, Length, Padding, Shadow, Theme,
+};
+
+use crate::message::Message;
+use crate::state::{JobTracker, TemplatePreview};
+use crate::templates::{TemplateKind, PLACEHOLDERS};
+use crate::theme::*;
+use crate::ui::common::*;
+
+// Template library with an editor for the selected template, shown in place of the add form
+pub fn templates_panel(state: &JobTracker) -> container::Container<'_, Message, Theme> {
+    let list = state.settings.templates.iter().enumerate().fold(
+        column![].spacing(6),
+        |list, (index, template)| {
+            let selected = state.editing_template == Some(index);
+            list.push(
+                row![
+                    button(text(&template.name).size(14))
+                        .style(move |theme, status| toggle_chip_style(theme, status, selected))
+                        .padding(Padding::from([6, 10]))
+                        .width(Length::Fill)
+                        .on_press(Message::EditTemplate(index)),
+                    text(template.kind.to_string())
+                        .size(12)
+                        .width(Length::Fixed(110.0))
+                        .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+                    button(text("✕").size(11))
+                        .style(delete_button_style)
+                        .padding(Padding::from([4, 8]))
+                        .on_press(Message::DeleteTemplate(index)),
+                ]
+                .spacing(10)
+                .align_y(Alignment::Center),
+            )
+        },
+    );
+
+    let library = column![
+        list,
+        row![
+            button(text("New Template").size(13))
+                .style(secondary_button_style)
+                .padding(Padding::from([6, 12]))
+                .on_press(Message::NewTemplate),
+        ]
+        .push_maybe(state.settings.templates.is_empty().then(|| {
+            button(text("Add Starter Templates").size(13))
+                .style(secondary_button_style)
+                .padding(Padding::from([6, 12]))
+                .on_press(Message::AddStarterTemplates)
+        }))
+        .spacing(8),
+    ]
+    .spacing(10)
+    .width(Length::FillPortion(2));
+
+    let editor: Element<'_, Message, Theme> =
+        match state.editing_template.and_then(|i| state.settings.templates.get(i)) {
+            Some(template) => column![
+                row![
+                    text_input("Template name", &template.name)
+                        .padding(8)
+                        .width(Length::Fill)
+                        .style(input_style)
+                        .on_input(Message::TemplateNameChanged),
+                    pick_list(TemplateKind::ALL, Some(template.kind), Message::TemplateKindSelected).padding(8),
+                ]
+                .spacing(10),
+                text_input("Email subject", &template.subject)
+                    .padding(8)
+                    .style(input_style)
+                    .on_input(Message::TemplateSubjectChanged),
+                text_editor(&state.template_body)
+                    .placeholder("Hi {{contact_first_name}}, ...")
+                    .height(Length::Fixed(220.0))
+                    .padding(8)
+                    .on_action(Message::TemplateBodyEdited),
+                placeholder_help(),
+            ]
+            .spacing(10)
+            .into(),
+            None => text("Pick a template to edit it")
+                .size(13)
+                .style(|_| text::Style { color: Some(kraken_secondary_text()) })
+                .into(),
+        };
+
+    let content = column![
+        text("Templates")
+            .size(18)
+            .style(|_| text::Style { color: Some(kraken_text()) }),
+        row![library, container(editor).width(Length::FillPortion(3))].spacing(20),
+        row![
+            Space::with_width(Length::Fill),
+            button(text("Done").size(14))
+                .style(primary_button_style)
+                .padding(Padding::from([8, 15]))
+                .on_press(Message::ToggleTemplates),
+        ],
+    ]
+    .spacing(15)
+    .padding(20);
+
+    container(content)
+        .width(Length::Fill)
+        .style(panel_style)
+}
+
+fn placeholder_help<'a>() -> Element<'a, Message, Theme> {
+    let names = PLACEHOLDERS
+        .iter()
+        .map(|(name, label)| format!("{{{{{}}}}} {}", name, label))
+        .collect::<Vec<_>>()
+        .join(" · ");
+    text(names)
+        .size(11)
+        .style(|_| text::Style { color: Some(kraken_secondary_text()) })
+        .into()
+}
+
+// A template rendered for one application, shown below its row
+pub fn template_preview<'a>(state: &'a JobTracker, preview: &'a TemplatePreview) -> Element<'a, Message, Theme> {
+    let templates = &state.settings.templates;
+    if templates.is_empty() {
+        return container(
+            text("Add templates from the Templates panel to write emails and cover letters from them")
+                .size(12)
+                .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+        )
+        .width(Length::Fill)
+        .padding(Padding::from([12, 18]))
+        .style(preview_panel_style)
+        .into();
+    }
+
+    let company = state.jobs.get(preview.job).map(|job| job.company.as_str()).unwrap_or_default();
+    let contacts = state.company_contacts(company);
+
+    let controls = row![
+        text("WRITE")
+            .size(12)
+            .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+        pick_list(
+            templates.as_slice(),
+            preview.template.and_then(|i| templates.get(i)).cloned(),
+            |picked| {
+                let index = templates.iter().position(|t| *t == picked).unwrap_or_default();
+                Message::PreviewTemplatePicked(index)
+            },
+        )
+        .placeholder("Template")
+        .padding(6),
+    ]
+    .push_maybe((!contacts.is_empty()).then(|| {
+        pick_list(
+            contacts,
+            preview.contact.and_then(|i| contacts.get(i)).cloned(),
+            |picked| {
+                let index = contacts.iter().position(|c| *c == picked).unwrap_or_default();
+                Message::PreviewContactPicked(index)
+            },
+        )
+        .placeholder("Contact")
+        .padding(6)
+    }))
+    .spacing(10)
+    .align_y(Alignment::Center);
+
+    let Some(rendered) = state.rendered_preview() else {
+        return container(controls)
+            .width(Length::Fill)
+            .padding(Padding::from([12, 18]))
+            .style(preview_panel_style)
+            .into();
+    };
+
+    let unfilled = (!rendered.unfilled.is_empty()).then(|| {
+        text(format!("Not filled in: {}", rendered.unfilled.join(", ")))
+            .size(12)
+            .style(|_| text::Style { color: Some(kraken_warning()) })
+    });
+    let exported = preview.exported.as_ref().map(|path| {
+        text(format!("Saved to {}", path))
+            .size(12)
+            .style(|_| text::Style { color: Some(kraken_secondary_text()) })
+    });
+
+    container(
+        column![controls]
+            .push_maybe((!rendered.subject.is_empty()).then(|| {
+                text(format!("Subject: {}", rendered.subject))
+                    .size(14)
+                    .style(|_| text::Style { color: Some(kraken_text()) })
+            }))
+            .push(
+                text(rendered.body)
+                    .size(14)
+                    .width(Length::Fill)
+                    .style(|_| text::Style { color: Some(kraken_text()) }),
+            )
+            .push_maybe(unfilled)
+            .push(
+                row![
+                    button(text("Copy").size(12))
+                        .style(secondary_button_style)
+                        .padding(Padding::from([5, 10]))
+                        .on_press(Message::CopyRendered),
+                    button(text("Export .txt").size(12))
+                        .style(secondary_button_style)
+                        .padding(Padding::from([5, 10]))
+                        .on_press(Message::ExportRendered(false)),
+                    button(text("Export .eml").size(12))
+                        .style(secondary_button_style)
+                        .padding(Padding::from([5, 10]))
+                        .on_press(Message::ExportRendered(true)),
+                ]
+                .push_maybe(exported)
+                .spacing(8)
+                .align_y(Alignment::Center),
+            )
+            .spacing(10),
+    )
+    .width(Length::Fill)
+    .padding(Padding::from([12, 18]))
+    .style(preview_panel_style)
+    .into()
+}
+
+fn preview_panel_style(_theme: &Theme) -> container::Style {
+    container::Style {
+        background: Some(Background::Color(kraken_background())),
+        text_color: Some(kraken_text()),
+        border: Border {
+            color: kraken_border(),
+            width: 1.0,
+            radius: 6.0.into(),
+        },
+        shadow: Shadow::default(),
+    }
+}
@@ -0,0 +1,118 @@
+use iced::{
+    widget::{button, column, row, text, text_input, Space},
+    Alignment, Element, Length, Padding, Theme,
+};
+
+use crate::data::MAX_TRASH_DAYS;
+use crate::message::Message;
+use crate::state::JobTracker;
+use crate::theme::*;
+use crate::ui::common::{delete_button_style, input_style, secondary_button_style};
+use crate::ui::stats::panel;
+
+// Shown above the archive, with a shortcut for archiving every closed application
+pub fn archive_banner(state: &JobTracker) -> Element<'_, Message, Theme> {
+    let closed = state
+        .jobs
+        .iter()
+        .filter(|job| !job.archived && !state.pipeline.is_active(&job.status))
+        .count();
+
+    panel(
+        row![
+            text("Archived applications are hidden from the list but still count in statistics and goals.")
+                .size(12)
+                .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+            Space::with_width(Length::Fill),
+            button(text(format!("Archive all closed ({})", closed)).size(12))
+                .style(secondary_button_style)
+                .padding(Padding::from([5, 12]))
+                .on_press_maybe((closed > 0).then_some(Message::ArchiveClosed)),
+        ]
+        .spacing(10)
+        .align_y(Alignment::Center)
+        .into(),
+    )
+}
+
+// Deleted applications, newest first, each restorable until it is purged
+pub fn trash_view(state: &JobTracker) -> Element<'_, Message, Theme> {
+    let header = row![
+        text("Deleted applications are kept for")
+            .size(12)
+            .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+        text_input("30", &state.trash_days_input)
+            .padding(5)
+            .width(Length::Fixed(50.0))
+            .style(input_style)
+            .on_input(Message::TrashDaysChanged)
+            .on_submit(Message::TrashDaysSubmitted),
+        text(format!("days (press Enter to apply, up to {}), then removed for good.", MAX_TRASH_DAYS))
+            .size(12)
+            .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+        Space::with_width(Length::Fill),
+        button(text("Empty Trash").size(12))
+            .style(delete_button_style)
+            .padding(Padding::from([5, 12]))
+            .on_press_maybe((!state.trash.is_empty()).then_some(Message::EmptyTrash)),
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center);
+
+    let rows = state
+        .trash
+        .iter()
+        .enumerate()
+        .rev()
+        .fold(column![].spacing(8), |rows, (index, trashed)| {
+            let purge_on = trashed
+                .purge_on(state.settings.trash_days)
+                .map(|on| on.format("%Y-%m-%d").to_string())
+                .unwrap_or_else(|| "-".to_string());
+            rows.push(
+                row![
+                    text(&trashed.job.company)
+                        .size(14)
+                        .width(Length::FillPortion(2))
+                        .style(|_| text::Style { color: Some(kraken_text()) }),
+                    text(&trashed.job.position)
+                        .size(14)
+                        .width(Length::FillPortion(3))
+                        .style(|_| text::Style { color: Some(kraken_text()) }),
+                    text(&trashed.job.status)
+                        .size(12)
+                        .width(Length::FillPortion(1))
+                        .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+                    text(format!("Deleted {}", trashed.deleted_at.split_whitespace().next().unwrap_or("")))
+                        .size(12)
+                        .width(Length::FillPortion(2))
+                        .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+                    text(format!("Purged {}", purge_on))
+                        .size(12)
+                        .width(Length::FillPortion(2))
+                        .style(|_| text::Style { color: Some(kraken_secondary_text()) }),
+                    button(text("Restore").size(12))
+                        .style(secondary_button_style)
+                        .padding(Padding::from([5, 12]))
+                        .on_press(Message::RestoreJob(index)),
+                    button(text("Delete Forever").size(12))
+                        .style(delete_button_style)
+                        .padding(Padding::from([5, 12]))
+                        .on_press(Message::PurgeJob(index)),
+                ]
+                .spacing(10)
+                .align_y(Alignment::Center),
+            )
+        });
+
+    let body: Element<'_, Message, Theme> = if state.trash.is_empty() {
+        text("The trash is empty")
+            .size(14)
+            .style(|_| text::Style { color: Some(kraken_secondary_text()) })
+            .into()
+    } else {
+        rows.into()
+    };
+
+    panel(column![header, body].spacing(15).into())
+}
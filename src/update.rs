@@ -1,9 +1,25 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
 use chrono::Local;
-use iced::Task;
+use iced::widget::text_editor;
+use iced::{event, keyboard, Event, Subscription, Task};
 
+use crate::calendar::{self, ViewMode};
+use crate::company::{self, Company};
+use crate::data::{JobApplication, LocationFilter, TrashedJob, MAX_TRASH_DAYS, SavedView, SourceFilter, StatusFilter, WorkModeFilter};
+use crate::description;
+use crate::documents::{self, DocumentKind};
+use crate::duplicates;
+use crate::goals::{Goal, NetworkingEntry};
+use crate::icalendar;
+use crate::keywords;
 use crate::message::Message;
-use crate::state::{FormState, JobTracker, SortOrder}; // Removed unused SortColumn import
+use crate::pipeline::{Pipeline, RequiredField, Stage, StageOutcome};
+use crate::state::{FormState, JobTracker, ListScope, MergeState, NewDeadline, NewDocument, NewEvent, TemplatePreview};
 use crate::storage;
+use crate::sync;
+use crate::templates::{self, Template, TemplateKind};
 
 pub fn update(state: &mut JobTracker, message: Message) -> Task<Message> {
     match message {
@@ -44,12 +60,135 @@ pub fn update(state: &mut JobTracker, message: Message) -> Task<Message> {
             }
             Task::none()
         },
-        Message::NotesChanged(value) => {
+        Message::NotesEdited(action) => {
             if state.editing_index.is_some() {
-                state.edit_form.notes = value;
-                state.has_unsaved_changes = true;
+                // Cursor movement and selection are not changes
+                if action.is_edit() {
+                    state.has_unsaved_changes = true;
+                }
+                state.edit_form.notes.perform(action);
+            } else {
+                state.form.notes.perform(action);
+            }
+            Task::none()
+        },
+        Message::ToggleNotes(index) => {
+            state.toggle_notes(index);
+            Task::none()
+        },
+        // Rating and pinning apply straight from the row, without entering edit mode
+        Message::SetPriority(index, stars) => {
+            if let Some(job) = state.jobs.get_mut(index) {
+                // Clicking the current rating again clears it
+                job.priority = if job.priority == Some(stars) { None } else { Some(stars) };
+                state.save_outside_edit();
+            }
+            Task::none()
+        },
+        Message::TogglePin(index) => {
+            if let Some(job) = state.jobs.get_mut(index) {
+                job.pinned = !job.pinned;
+                state.save_outside_edit();
+            }
+            Task::none()
+        },
+        Message::DescriptionEdited(action) => {
+            if state.editing_index.is_some() {
+                if action.is_edit() {
+                    state.has_unsaved_changes = true;
+                }
+                state.edit_form.description.perform(action);
+            } else {
+                state.form.description.perform(action);
+            }
+            Task::none()
+        },
+        Message::DescriptionPathChanged(path) => {
+            if state.editing_index.is_some() {
+                state.edit_form.description_path = path;
             } else {
-                state.form.notes = value;
+                state.form.description_path = path;
+            }
+            Task::none()
+        },
+        Message::ImportDescription => {
+            let form = if state.editing_index.is_some() { &mut state.edit_form } else { &mut state.form };
+            let path = PathBuf::from(form.description_path.trim());
+            if path.as_os_str().is_empty() {
+                return Task::none();
+            }
+            form.importing_description = true;
+
+            // PDF extraction can take a while, so keep it off the UI thread
+            Task::perform(
+                async move {
+                    tokio::task::spawn_blocking(move || description::import_file(&path))
+                        .await
+                        .map_err(|err| err.to_string())
+                        .and_then(|result| result.map_err(|err| err.to_string()))
+                },
+                Message::DescriptionImported,
+            )
+        },
+        Message::DescriptionImported(result) => {
+            let editing = state.editing_index.is_some();
+            let form = if editing { &mut state.edit_form } else { &mut state.form };
+            form.importing_description = false;
+            match result {
+                Ok(text) => {
+                    form.description = text_editor::Content::with_text(&text);
+                    form.description_path.clear();
+                    if editing {
+                        state.has_unsaved_changes = true;
+                    }
+                }
+                Err(err) => state.error_message = Some(format!("Error importing job description: {}", err)),
+            }
+            Task::none()
+        },
+        Message::ToggleDescription(index) => {
+            state.reading_description = if state.reading_description == Some(index) { None } else { Some(index) };
+            // Compare against the resume sent with this application unless another is picked
+            state.keyword_resume = state.jobs.get(index).and_then(|job| job.resume.clone());
+            state.keyword_report = None;
+            Task::none()
+        },
+        Message::KeywordResumePicked(id) => {
+            state.keyword_resume = Some(id);
+            state.keyword_report = None;
+            Task::none()
+        },
+        Message::AnalyzeKeywords(index) => {
+            let Some(job) = state.jobs.get(index) else {
+                return Task::none();
+            };
+            let Some(resume) = state.keyword_resume.as_deref().and_then(|id| state.document(id)) else {
+                return Task::none();
+            };
+            let description = job.description.clone();
+            let path = resume.path();
+            state.analyzing_keywords = true;
+
+            // Reading the resume may mean parsing a PDF, so do it off the UI thread
+            Task::perform(
+                async move {
+                    tokio::task::spawn_blocking(move || {
+                        description::import_file(&path)
+                            .map(|resume_text| keywords::compare(&description, &resume_text))
+                            .map_err(|err| err.to_string())
+                    })
+                    .await
+                    .map_err(|err| err.to_string())
+                    .and_then(|result| result)
+                },
+                move |result| Message::KeywordReportReady(index, result),
+            )
+        },
+        Message::KeywordReportReady(index, result) => {
+            state.analyzing_keywords = false;
+            match result {
+                Ok(report) => state.keyword_report = Some((index, report)),
+                Err(err) => state.error_message = Some(format!("Error reading resume: {}", err)),
             }
             Task::none()
         },
@@ -74,10 +213,24 @@ pub fn update(state: &mut JobTracker, message: Message) -> Task<Message> {
         Message::AddJob => {
             // Only add if required fields are filled
             if state.form.is_valid() {
+                // The add form has no change note, so only the other requirements apply
+                let status = state.form.status.clone().unwrap_or_default();
+                let missing = state.pipeline.stage(&status)
+                    .and_then(|stage| state.form.missing_requirement(stage))
+                    .filter(|field| *field != RequiredField::Note);
+                if let Some(field) = missing {
+                    state.error_message = Some(format!("\"{}\" requires: {}", status, field));
+                    return Task::none();
+                }
+
                 if let Some(mut job) = state.form.to_job() {
                     // Add timestamp
                     let now = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
                     job.last_updated = Some(now);
+                    job.company = company::canonical_name(&state.companies, &job.company);
+                    job.record_status_change(None, "", false);
+                    let first_stage = state.pipeline.stages.first().map(|stage| stage.name.clone());
+                    finish_closure_reason(&mut job, &state.pipeline, first_stage);
 
                     state.jobs.push(job);
                     state.has_unsaved_changes = true;  // Mark as changed after adding job
@@ -91,6 +244,7 @@ pub fn update(state: &mut JobTracker, message: Message) -> Task<Message> {
                     is_expanded: true, // Keep form open for multiple adds
                     ..Default::default()
                 };
+                return scan_duplicates(state);
             }
             Task::none()
         },
@@ -112,39 +266,276 @@ pub fn update(state: &mut JobTracker, message: Message) -> Task<Message> {
         Message::SaveEdit => {
             if let Some(index) = state.editing_index {
                 if index < state.jobs.len() && state.edit_form.is_valid() {
-                    // Update the job with the edited values
-                    if let Some(mut job) = state.edit_form.to_job() {
-                        // Update timestamp
-                        let now = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
-                        job.last_updated = Some(now);
+                    let previous = state.jobs[index].status.clone();
+                    let next = state.edit_form.status.clone().unwrap_or_default();
+                    let mut forced = false;
+
+                    if previous != next {
+                        // Disallowed moves need an explicit override, which is recorded
+                        if let Err(err) = state.pipeline.validate_transition(&previous, &next) {
+                            if !state.edit_form.force_transition {
+                                state.error_message = Some(format!("{} - tick \"Force status change\" to override", err));
+                                return Task::none();
+                            }
+                            forced = true;
+                        }
 
-                        state.jobs[index] = job;
-                        state.has_unsaved_changes = true;  // Mark as changed after editing
+                        let missing = state.pipeline.stage(&next)
+                            .and_then(|stage| state.edit_form.missing_requirement(stage));
+                        if let Some(field) = missing {
+                            state.error_message = Some(format!("\"{}\" requires: {}", next, field));
+                            return Task::none();
+                        }
+                    }
 
-                        // Save after editing
-                        state.save();
+                    // Update the job with the edited values, keeping fields the form does not cover
+                    let job = &mut state.jobs[index];
+                    state.edit_form.apply_to(job);
+                    job.company = company::canonical_name(&state.companies, &job.company);
+                    finish_closure_reason(job, &state.pipeline, Some(previous.clone()));
+                    if previous != next {
+                        job.record_status_change(Some(previous), &state.edit_form.transition_note, forced);
                     }
+
+                    state.has_unsaved_changes = true;  // Mark as changed after editing
+                    state.refresh_notes();
+                    state.error_message = None;
+
+                    // Save after editing
+                    state.save();
                 }
 
                 // Clear editing state
                 state.editing_index = None;
                 state.edit_form = FormState::default();
+                return scan_duplicates(state);
+            }
+            Task::none()
+        },
+        Message::ReasonStageSelected(stage) => {
+            if state.editing_index.is_some() {
+                state.edit_form.reason_stage = Some(stage);
+                state.has_unsaved_changes = true;
+            } else {
+                state.form.reason_stage = Some(stage);
+            }
+            Task::none()
+        },
+        Message::ReasonCategorySelected(category) => {
+            if state.editing_index.is_some() {
+                state.edit_form.reason_category = Some(category);
+                state.has_unsaved_changes = true;
+            } else {
+                state.form.reason_category = Some(category);
+            }
+            Task::none()
+        },
+        Message::ReasonDetailsChanged(value) => {
+            if state.editing_index.is_some() {
+                state.edit_form.reason_details = value;
+                state.has_unsaved_changes = true;
+            } else {
+                state.form.reason_details = value;
+            }
+            Task::none()
+        },
+        Message::ToggleReasonStats => {
+            state.show_reason_stats = !state.show_reason_stats;
+            Task::none()
+        },
+        Message::ToggleActivity => {
+            state.show_activity = !state.show_activity;
+            Task::none()
+        },
+        Message::ToggleTiming => {
+            state.show_timing = !state.show_timing;
+            Task::none()
+        },
+        Message::SourceChanged(source) => {
+            if state.editing_index.is_some() {
+                state.has_unsaved_changes = true;
+            }
+            state.active_form_mut().source = source;
+            Task::none()
+        },
+        Message::FilterSourceChanged(source) => {
+            state.filter_source = source;
+            Task::none()
+        },
+        Message::CityChanged(city) => {
+            if state.editing_index.is_some() {
+                state.has_unsaved_changes = true;
+            }
+            state.active_form_mut().city = city;
+            Task::none()
+        },
+        Message::RegionChanged(region) => {
+            if state.editing_index.is_some() {
+                state.has_unsaved_changes = true;
+            }
+            state.active_form_mut().region = region;
+            Task::none()
+        },
+        Message::CountryChanged(country) => {
+            if state.editing_index.is_some() {
+                state.has_unsaved_changes = true;
+            }
+            state.active_form_mut().country = country;
+            Task::none()
+        },
+        // Choosing the selected option again clears it
+        Message::WorkModeSelected(mode) => {
+            if state.editing_index.is_some() {
+                state.has_unsaved_changes = true;
+            }
+            let form = state.active_form_mut();
+            form.work_mode = if form.work_mode == Some(mode) { None } else { Some(mode) };
+            Task::none()
+        },
+        Message::SponsorshipSelected(sponsorship) => {
+            if state.editing_index.is_some() {
+                state.has_unsaved_changes = true;
+            }
+            let form = state.active_form_mut();
+            form.visa_sponsorship = if form.visa_sponsorship == Some(sponsorship) { None } else { Some(sponsorship) };
+            Task::none()
+        },
+        Message::FilterLocationChanged(location) => {
+            state.filter_location = location;
+            Task::none()
+        },
+        Message::FilterWorkModeChanged(mode) => {
+            state.filter_work_mode = mode;
+            Task::none()
+        },
+        Message::ToggleSponsorshipFilter => {
+            state.sponsorship_only = !state.sponsorship_only;
+            Task::none()
+        },
+        Message::GroupByChanged(group_by) => {
+            state.group_by = group_by;
+            Task::none()
+        },
+        Message::ToggleSources => {
+            state.show_sources = !state.show_sources;
+            Task::none()
+        },
+        Message::NewSourceChanged(source) => {
+            state.new_source_input = source;
+            Task::none()
+        },
+        Message::AddSource => {
+            let source = state.new_source_input.trim().to_string();
+            if source.is_empty() || state.settings.sources.iter().any(|s| s.eq_ignore_ascii_case(&source)) {
+                return Task::none();
+            }
+            state.settings.sources.push(source);
+            state.new_source_input.clear();
+            state.save_settings();
+            Task::none()
+        },
+        Message::ToggleGoals => {
+            state.show_goals = !state.show_goals;
+            if !state.show_goals && state.has_unsaved_changes {
+                state.save();
+            }
+            Task::none()
+        },
+        Message::NewGoalMetricSelected(metric) => {
+            state.new_goal.metric = metric;
+            Task::none()
+        },
+        Message::NewGoalPeriodSelected(period) => {
+            state.new_goal.period = period;
+            Task::none()
+        },
+        Message::NewGoalTargetChanged(target) => {
+            state.new_goal_target = target;
+            Task::none()
+        },
+        Message::AddGoal => {
+            let Some(target) = state.new_goal_target.trim().parse::<u32>().ok().filter(|t| *t > 0) else {
+                state.error_message = Some("Goal targets must be a whole number above zero".to_string());
+                return Task::none();
+            };
+            let goal = Goal { target, ..state.new_goal.clone() };
+
+            // One goal per metric and period; adding again replaces the target
+            match state
+                .settings
+                .goals
+                .iter_mut()
+                .find(|g| g.metric == goal.metric && g.period == goal.period)
+            {
+                Some(existing) => existing.target = goal.target,
+                None => state.settings.goals.push(goal),
+            }
+            state.new_goal_target.clear();
+            state.error_message = None;
+            state.save_settings();
+            Task::none()
+        },
+        Message::RemoveGoal(index) => {
+            if index < state.settings.goals.len() {
+                state.settings.goals.remove(index);
+                state.save_settings();
+            }
+            Task::none()
+        },
+        Message::NetworkingNoteChanged(note) => {
+            state.networking_note = note;
+            Task::none()
+        },
+        Message::LogNetworkingContact => {
+            state.networking.push(NetworkingEntry {
+                date: Local::now().format("%Y-%m-%d").to_string(),
+                note: state.networking_note.trim().to_string(),
+            });
+            state.networking_note.clear();
+            state.has_unsaved_changes = true;
+            Task::none()
+        },
+        Message::RemoveNetworkingEntry(index) => {
+            if index < state.networking.len() {
+                state.networking.remove(index);
+                state.has_unsaved_changes = true;
+            }
+            Task::none()
+        },
+        Message::RemoveSource(index) => {
+            // Applications keep their recorded source; it just stops being offered
+            if index < state.settings.sources.len() {
+                state.settings.sources.remove(index);
+                state.save_settings();
             }
             Task::none()
         },
+        Message::TransitionNoteChanged(value) => {
+            state.edit_form.transition_note = value;
+            Task::none()
+        },
+        Message::ForceTransitionToggled(force) => {
+            state.edit_form.force_transition = force;
+            Task::none()
+        },
         Message::CancelEdit => {
             // Clear editing state without saving changes
             state.editing_index = None;
             state.edit_form = FormState::default();
             Task::none()
         },
+        // Deleting moves the application to the trash, where it can be restored until purged
         Message::DeleteJob(index) => {
             if index < state.jobs.len() {
-                // Create backup before deletion
-                let _ = storage::backup_data();
-
-                state.jobs.remove(index);
-                state.has_unsaved_changes = true;  // Mark as changed after deletion
+                let job = state.jobs.remove(index);
+                state.trash.push(TrashedJob {
+                    job,
+                    deleted_at: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+                });
+                state.expanded_notes = None;
+                state.reading_description = None;
+                state.keyword_report = None;
+                state.template_preview = None;
 
                 // If we were editing this index, clear the editing state
                 if state.editing_index == Some(index) {
@@ -158,8 +549,13 @@ pub fn update(state: &mut JobTracker, message: Message) -> Task<Message> {
                     }
                 }
 
-                // Save after deleting
-                state.save();
+                // Indices have shifted, so any open merge no longer points at the right jobs
+                state.merge = None;
+                state.duplicate_pairs.clear();
+
+                // Save after trashing
+                state.save_outside_edit();
+                return scan_duplicates(state);
             }
             Task::none()
         },
@@ -168,11 +564,16 @@ pub fn update(state: &mut JobTracker, message: Message) -> Task<Message> {
             Task::none()
         },
         Message::LoadData => {
-            match storage::load_jobs() {
-                Ok(jobs) => {
-                    state.jobs = jobs;
+            match storage::load_data() {
+                Ok(data) => {
+                    state.on_disk = data.clone();
+                    state.disk_modified = storage::data_modified();
+                    state.apply_data(data);
+                    state.sync = None;
+                    state.purge_expired_trash();
                     state.error_message = None;
                     state.has_unsaved_changes = false;  // Reset after loading
+                    return scan_duplicates(state);
                 },
                 Err(err) => {
                     state.error_message = Some(format!("Error loading data: {}", err));
@@ -180,6 +581,101 @@ pub fn update(state: &mut JobTracker, message: Message) -> Task<Message> {
             }
             Task::none()
         },
+        Message::CheckDataFile => {
+            let modified = storage::data_modified();
+            // Nothing new on disk, or a decision on earlier changes is still pending
+            if modified == state.disk_modified || state.sync.is_some() {
+                return Task::none();
+            }
+            // A missing file (moved away, or mid-replace by a sync tool) is not a deletion of everything
+            // in it. Saving may write it again, and a file that reappears is merged against the last one seen
+            if modified.is_none() {
+                state.disk_modified = None;
+                return Task::none();
+            }
+
+            let theirs = match storage::load_data() {
+                Ok(data) => data,
+                Err(err) => {
+                    // Stop holding back saves for a file that cannot be read
+                    state.disk_modified = modified;
+                    state.error_message = Some(format!("The data file changed on disk but could not be read: {}", err));
+                    return Task::none();
+                }
+            };
+            // Loading can itself write the file, e.g. to assign ids. If it vanished while being read,
+            // the empty data read in its place is ignored and the next check looks again
+            state.disk_modified = storage::data_modified();
+            if state.disk_modified.is_none() {
+                return Task::none();
+            }
+
+            let pending = state.pending_edit().map(str::to_string);
+            let reconciled = sync::reconcile(&state.on_disk, &state.snapshot(), theirs.clone(), pending.as_deref());
+            state.on_disk = theirs;
+            if !reconciled.kept_mine.is_empty() {
+                state.error_message = Some(format!(
+                    "The data file also changed these, and the versions here were kept: {}",
+                    reconciled.kept_mine.join(", ")
+                ));
+            }
+            if reconciled.conflicts.is_empty() {
+                let merged = reconciled.data;
+                let local_changes = merged != state.on_disk;
+                state.apply_data(merged);
+                // An open but untouched edit form picks up the file's version of its application
+                if pending.is_none() {
+                    if let Some(job) = state.editing_index.and_then(|index| state.jobs.get(index)) {
+                        state.edit_form = FormState::from_job(job);
+                    }
+                }
+                // Changes made here are written back on top of the file's; without any, only an
+                // open edit is left unsaved
+                if local_changes {
+                    state.save();
+                } else {
+                    state.has_unsaved_changes = pending.is_some();
+                    state.save_deferred = false;
+                }
+                return scan_duplicates(state);
+            }
+            state.sync = Some(reconciled);
+            Task::none()
+        },
+        Message::ResolveConflict(index, keep_mine) => {
+            if let Some(conflict) = state.sync.as_mut().and_then(|sync| sync.conflicts.get_mut(index)) {
+                conflict.keep_mine = keep_mine;
+            }
+            Task::none()
+        },
+        Message::ResolveAllConflicts(keep_mine) => {
+            if let Some(sync) = state.sync.as_mut() {
+                for conflict in &mut sync.conflicts {
+                    conflict.keep_mine = keep_mine;
+                }
+            }
+            Task::none()
+        },
+        Message::ApplyResolution => {
+            let Some(reconciled) = state.sync.take() else {
+                return Task::none();
+            };
+
+            // An open edit whose application was replaced by the file's version starts over from it
+            let replaced_edit = state.pending_edit().is_some_and(|id| {
+                reconciled.conflicts.iter().any(|conflict| conflict.id == id && !conflict.keep_mine)
+            });
+
+            state.apply_data(sync::resolve(reconciled));
+            if replaced_edit {
+                match state.editing_index.and_then(|index| state.jobs.get(index)) {
+                    Some(job) => state.edit_form = FormState::from_job(job),
+                    None => state.edit_form = FormState::default(),
+                }
+            }
+            state.save();
+            scan_duplicates(state)
+        },
         Message::ErrorDismissed => {
             state.error_message = None;
             Task::none()
@@ -187,31 +683,961 @@ pub fn update(state: &mut JobTracker, message: Message) -> Task<Message> {
         // Filter-related message handlers (don't affect saved state)
         Message::SearchQueryChanged(query) => {
             state.search_query = query;
+            state.refresh_search();
             Task::none()
         },
         Message::FilterStatusChanged(status) => {
-            state.filter_status = Some(status);
+            state.filter_status = status;
+            Task::none()
+        },
+        Message::ToggleRelevanceSort => {
+            state.sort_by_relevance = !state.sort_by_relevance;
+            Task::none()
+        },
+        Message::DateFromChanged(value) => {
+            state.date_from = value;
+            Task::none()
+        },
+        Message::DateToChanged(value) => {
+            state.date_to = value;
+            Task::none()
+        },
+        Message::StaleDaysChanged(value) => {
+            // Only accept digits so the field always holds a day count
+            if value.chars().all(|c| c.is_ascii_digit()) {
+                state.stale_days = value;
+            }
+            Task::none()
+        },
+        Message::DatePresetSelected(preset) => {
+            let (from, to) = preset.range(Local::now().date_naive());
+            state.date_from = from.format("%Y-%m-%d").to_string();
+            state.date_to = to.format("%Y-%m-%d").to_string();
             Task::none()
         },
         Message::ClearFilters => {
             state.search_query = String::new();
-            state.filter_status = None;
+            state.search_matches.clear();
+            state.sort_by_relevance = false;
+            state.filter_status = StatusFilter::All;
+            state.filter_source = SourceFilter::All;
+            state.filter_location = LocationFilter::All;
+            state.filter_work_mode = WorkModeFilter::All;
+            state.sponsorship_only = false;
+            state.date_from.clear();
+            state.date_to.clear();
+            state.stale_days.clear();
+            state.day_filter = None;
             Task::none()
         },
         Message::SortBy(column) => {
-            // If already sorting by this column, toggle order
-            if state.sort_column == column {
-                state.sort_order = match state.sort_order {
-                    SortOrder::Ascending => SortOrder::Descending,
-                    SortOrder::Descending => SortOrder::None,
-                    SortOrder::None => SortOrder::Ascending,
+            let additive = state.modifiers.shift();
+            state.toggle_sort(column, additive);
+            Task::none()
+        },
+        Message::ModifiersChanged(modifiers) => {
+            state.modifiers = modifiers;
+            Task::none()
+        },
+        Message::TogglePipelineEditor => {
+            // A rejected rename keeps the editor open so it can be fixed
+            if state.show_pipeline_editor && !finish_stage_rename(state) {
+                return Task::none();
+            }
+            state.show_pipeline_editor = !state.show_pipeline_editor;
+            // Persist pipeline edits once the editor is closed
+            if !state.show_pipeline_editor && state.has_unsaved_changes {
+                state.save();
+            }
+            Task::none()
+        },
+        Message::StageNameChanged(index, name) => {
+            // Moving on to another stage's name applies the one left behind
+            if state.stage_rename.as_ref().is_some_and(|(editing, _)| *editing != index) {
+                finish_stage_rename(state);
+                state.stage_rename = None;
+            }
+            state.stage_rename = Some((index, name));
+            Task::none()
+        },
+        Message::StageNameSubmitted => {
+            finish_stage_rename(state);
+            Task::none()
+        },
+        Message::StageColorChanged(index, color) => {
+            if let Some(stage) = state.pipeline.stages.get_mut(index) {
+                stage.color = color;
+                state.has_unsaved_changes = true;
+            }
+            Task::none()
+        },
+        Message::StageTerminalToggled(index, terminal) => {
+            if let Some(stage) = state.pipeline.stages.get_mut(index) {
+                stage.terminal = terminal;
+                state.has_unsaved_changes = true;
+            }
+            Task::none()
+        },
+        Message::StageInterviewToggled(index, interview) => {
+            if let Some(stage) = state.pipeline.stages.get_mut(index) {
+                stage.interview = Some(interview);
+                state.has_unsaved_changes = true;
+            }
+            Task::none()
+        },
+        Message::StageOutcomeSelected(index, outcome) => {
+            if let Some(stage) = state.pipeline.stages.get_mut(index) {
+                stage.outcome = outcome;
+                state.has_unsaved_changes = true;
+            }
+            Task::none()
+        },
+        Message::MoveStage(index, up) => {
+            // Stage indices may shift, so settle the name being typed first
+            finish_stage_rename(state);
+            state.stage_rename = None;
+            let target = if up { index.checked_sub(1) } else { Some(index + 1) };
+            if let Some(target) = target.filter(|&t| t < state.pipeline.stages.len()) {
+                state.pipeline.stages.swap(index, target);
+                state.has_unsaved_changes = true;
+            }
+            Task::none()
+        },
+        Message::ToggleStageRules(index) => {
+            state.editing_rules_for = if state.editing_rules_for == Some(index) { None } else { Some(index) };
+            Task::none()
+        },
+        Message::StageAnyTransitionToggled(index, any) => {
+            let names = state.pipeline.names();
+            if let Some(stage) = state.pipeline.stages.get_mut(index) {
+                // Switching to an explicit list starts from every other stage
+                stage.allowed_next = if any {
+                    None
+                } else {
+                    Some(names.into_iter().filter(|name| *name != stage.name).collect())
                 };
-            } else {
-                // New column, start with ascending
-                state.sort_column = column;
-                state.sort_order = SortOrder::Ascending;
+                state.has_unsaved_changes = true;
             }
             Task::none()
         },
-    }
+        Message::StageTransitionToggled(index, next) => {
+            if let Some(allowed) = state.pipeline.stages.get_mut(index).and_then(|s| s.allowed_next.as_mut()) {
+                if let Some(pos) = allowed.iter().position(|name| *name == next) {
+                    allowed.remove(pos);
+                } else {
+                    allowed.push(next);
+                }
+                state.has_unsaved_changes = true;
+            }
+            Task::none()
+        },
+        Message::StageRequirementToggled(index, field, required) => {
+            if let Some(stage) = state.pipeline.stages.get_mut(index) {
+                stage.requires.retain(|f| *f != field);
+                if required {
+                    stage.requires.push(field);
+                }
+                state.has_unsaved_changes = true;
+            }
+            Task::none()
+        },
+        Message::AddStage => {
+            // Pick a placeholder name that does not clash with an existing stage
+            let name = (1..)
+                .map(|n| format!("New Stage {}", n))
+                .find(|name| state.pipeline.stage(name).is_none())
+                .unwrap_or_default();
+            state.pipeline.stages.push(Stage::new(&name, "#808080", false, StageOutcome::Pending));
+            state.has_unsaved_changes = true;
+            Task::none()
+        },
+        Message::RemoveStage(index) => {
+            // Stage indices may shift, so settle the name being typed first
+            finish_stage_rename(state);
+            state.stage_rename = None;
+            match state.remove_stage(index) {
+                Ok(()) => state.has_unsaved_changes = true,
+                Err(err) => state.error_message = Some(err),
+            }
+            Task::none()
+        },
+        Message::PipelinePresetSelected(preset) => {
+            // Stage indices may shift, so settle the name being typed first
+            finish_stage_rename(state);
+            state.stage_rename = None;
+            // Stages still in use are kept at the end so no application loses its status
+            state.pipeline = preset.pipeline();
+            state.pipeline.adopt_statuses(&state.jobs);
+            state.has_unsaved_changes = true;
+            Task::none()
+        },
+        Message::ApplyView(name) => {
+            if let Some(view) = state.all_views().into_iter().find(|v| v.name == name) {
+                state.apply_view(&view);
+            }
+            Task::none()
+        },
+        Message::ViewNameChanged(name) => {
+            state.view_name_input = name;
+            Task::none()
+        },
+        Message::SaveCurrentView => {
+            let name = state.view_name_input.trim().to_string();
+            if name.is_empty() {
+                return Task::none();
+            }
+            if SavedView::builtins().iter().any(|v| v.name == name) {
+                state.error_message = Some(format!("\"{}\" is a built-in view name", name));
+                return Task::none();
+            }
+
+            // Saving under an existing name overwrites that view
+            let view = state.current_view(&name);
+            match state.settings.saved_views.iter_mut().find(|v| v.name == name) {
+                Some(existing) => *existing = view,
+                None => state.settings.saved_views.push(view),
+            }
+            state.view_name_input.clear();
+            state.save_settings();
+            Task::none()
+        },
+        Message::DeleteView(name) => {
+            state.settings.saved_views.retain(|v| v.name != name);
+            state.save_settings();
+            Task::none()
+        },
+        Message::DuplicatesFound(pairs) => {
+            state.duplicate_pairs = pairs;
+            if state.duplicate_pairs.is_empty() {
+                state.show_duplicates = false;
+            }
+            Task::none()
+        },
+        Message::ToggleDuplicates => {
+            state.show_duplicates = !state.show_duplicates;
+            Task::none()
+        },
+        Message::StartMerge(left, right) => {
+            if left != right && left < state.jobs.len() && right < state.jobs.len() {
+                // The merge screen replaces the add form and any open editor
+                state.merge = Some(MergeState { left, right, take_right: Vec::new() });
+                finish_stage_rename(state);
+                state.stage_rename = None;
+                state.show_pipeline_editor = false;
+                state.editing_index = None;
+                state.edit_form = FormState::default();
+            }
+            Task::none()
+        },
+        Message::MergeFieldPicked(field, take_right) => {
+            if let Some(merge) = state.merge.as_mut() {
+                merge.take_right.retain(|f| *f != field);
+                if take_right {
+                    merge.take_right.push(field);
+                }
+            }
+            Task::none()
+        },
+        Message::ConfirmMerge => {
+            let Some(merge) = state.merge.take() else {
+                return Task::none();
+            };
+            if merge.left == merge.right || merge.left >= state.jobs.len() || merge.right >= state.jobs.len() {
+                return Task::none();
+            }
+
+            // Create backup before merging, since one record is removed
+            let _ = storage::backup_data();
+
+            state.jobs[merge.left] = duplicates::merge_jobs(
+                &state.jobs[merge.left],
+                &state.jobs[merge.right],
+                &merge.take_right,
+            );
+            state.jobs.remove(merge.right);
+            state.expanded_notes = None;
+            state.reading_description = None;
+            state.keyword_report = None;
+            state.template_preview = None;
+            state.duplicate_pairs.clear();
+            state.has_unsaved_changes = true;
+
+            state.save();
+            scan_duplicates(state)
+        },
+        Message::CancelMerge => {
+            state.merge = None;
+            Task::none()
+        },
+        Message::CompanySuggestionPicked(name) => {
+            if state.editing_index.is_some() {
+                state.edit_form.company = name;
+                state.has_unsaved_changes = true;
+            } else {
+                state.form.company = name;
+            }
+            Task::none()
+        },
+        Message::ToggleCompanies => {
+            state.show_companies = !state.show_companies;
+            if !state.show_companies && state.has_unsaved_changes {
+                state.save();
+            }
+            Task::none()
+        },
+        Message::NewCompanyNameChanged(name) => {
+            state.new_company_input = name;
+            Task::none()
+        },
+        Message::AddCompany => {
+            let name = state.new_company_input.trim().to_string();
+            if name.is_empty() {
+                return Task::none();
+            }
+            if let Some(existing) = company::find_company(&state.companies, &name) {
+                state.error_message = Some(format!(
+                    "\"{}\" is already registered as \"{}\"",
+                    name, state.companies[existing].name
+                ));
+                return Task::none();
+            }
+
+            state.companies.push(Company::new(&name));
+            company::normalize_job_companies(&state.companies, &mut state.jobs);
+            state.new_company_input.clear();
+            state.has_unsaved_changes = true;
+            Task::none()
+        },
+        Message::RegisterAllCompanies => {
+            // First spelling seen becomes the canonical name for each group
+            for job in &state.jobs {
+                if !job.company.trim().is_empty() && company::find_company(&state.companies, &job.company).is_none() {
+                    state.companies.push(Company::new(&job.company));
+                }
+            }
+            company::normalize_job_companies(&state.companies, &mut state.jobs);
+            state.has_unsaved_changes = true;
+            Task::none()
+        },
+        Message::RemoveCompany(index) => {
+            // Applications keep their company name; only the registry entry goes
+            if index < state.companies.len() {
+                state.companies.remove(index);
+                state.open_company = None;
+                state.has_unsaved_changes = true;
+            }
+            Task::none()
+        },
+        Message::OpenCompany(name) => {
+            state.show_companies = false;
+            state.open_company(&name);
+            Task::none()
+        },
+        Message::RegisterCompany(name) => {
+            state.register_company(&name);
+            Task::none()
+        },
+        Message::CloseCompany => {
+            state.open_company = None;
+            // Persist registry edits when leaving the page
+            if state.has_unsaved_changes {
+                state.save();
+            }
+            Task::none()
+        },
+        Message::CompanyRenameChanged(name) => {
+            state.company_page.rename = name;
+            Task::none()
+        },
+        Message::RenameCompany(index) => {
+            let name = state.company_page.rename.clone();
+            match state.rename_company(index, &name) {
+                Ok(()) => state.has_unsaved_changes = true,
+                Err(err) => state.error_message = Some(err),
+            }
+            Task::none()
+        },
+        Message::CompanyWebsiteChanged(index, website) => {
+            if let Some(entry) = state.companies.get_mut(index) {
+                entry.website = Some(website).filter(|w| !w.is_empty());
+                state.has_unsaved_changes = true;
+            }
+            Task::none()
+        },
+        Message::CompanyIndustryChanged(index, industry) => {
+            if let Some(entry) = state.companies.get_mut(index) {
+                entry.industry = industry;
+                state.has_unsaved_changes = true;
+            }
+            Task::none()
+        },
+        Message::CompanySizeSelected(index, size) => {
+            if let Some(entry) = state.companies.get_mut(index) {
+                entry.size = Some(size);
+                state.has_unsaved_changes = true;
+            }
+            Task::none()
+        },
+        Message::CompanyNotesChanged(index, notes) => {
+            if let Some(entry) = state.companies.get_mut(index) {
+                entry.notes = notes;
+                state.has_unsaved_changes = true;
+            }
+            Task::none()
+        },
+        Message::AliasInputChanged(alias) => {
+            state.company_page.alias = alias;
+            Task::none()
+        },
+        Message::AddAlias(index) => {
+            let alias = state.company_page.alias.trim().to_string();
+            if alias.is_empty() || index >= state.companies.len() {
+                return Task::none();
+            }
+            if let Some(other) = company::find_company(&state.companies, &alias).filter(|&i| i != index) {
+                state.error_message = Some(format!(
+                    "\"{}\" already refers to \"{}\"",
+                    alias, state.companies[other].name
+                ));
+                return Task::none();
+            }
+
+            state.companies[index].aliases.push(alias);
+            company::normalize_job_companies(&state.companies, &mut state.jobs);
+            state.company_page.alias.clear();
+            state.has_unsaved_changes = true;
+            Task::none()
+        },
+        Message::RemoveAlias(index, alias_index) => {
+            if let Some(entry) = state.companies.get_mut(index) {
+                if alias_index < entry.aliases.len() {
+                    entry.aliases.remove(alias_index);
+                    state.has_unsaved_changes = true;
+                }
+            }
+            Task::none()
+        },
+        Message::ContactNameChanged(name) => {
+            state.company_page.contact.name = name;
+            Task::none()
+        },
+        Message::ContactRoleChanged(role) => {
+            state.company_page.contact.role = role;
+            Task::none()
+        },
+        Message::ContactEmailChanged(email) => {
+            state.company_page.contact.email = email;
+            Task::none()
+        },
+        Message::AddContact(index) => {
+            if state.company_page.contact.name.trim().is_empty() {
+                return Task::none();
+            }
+            if let Some(entry) = state.companies.get_mut(index) {
+                entry.contacts.push(std::mem::take(&mut state.company_page.contact));
+                state.has_unsaved_changes = true;
+            }
+            Task::none()
+        },
+        Message::RemoveContact(index, contact_index) => {
+            if let Some(entry) = state.companies.get_mut(index) {
+                if contact_index < entry.contacts.len() {
+                    entry.contacts.remove(contact_index);
+                    state.has_unsaved_changes = true;
+                }
+            }
+            Task::none()
+        },
+        Message::ToggleDocuments => {
+            state.show_documents = !state.show_documents;
+            Task::none()
+        },
+        Message::DocumentPathChanged(path) => {
+            state.new_document.path = path;
+            Task::none()
+        },
+        Message::DocumentNameChanged(name) => {
+            state.new_document.name = name;
+            Task::none()
+        },
+        Message::DocumentKindSelected(kind) => {
+            state.new_document.kind = kind;
+            Task::none()
+        },
+        Message::AddDocument => {
+            let new = &state.new_document;
+            let path = PathBuf::from(new.path.trim());
+            if path.as_os_str().is_empty() {
+                return Task::none();
+            }
+
+            match documents::add_to_library(&path, &new.name, new.kind, &state.documents) {
+                Ok(document) => {
+                    state.documents.push(document);
+                    state.new_document = NewDocument {
+                        kind: state.new_document.kind,
+                        ..Default::default()
+                    };
+                    state.has_unsaved_changes = true;
+                    state.save();
+                }
+                Err(err) => state.error_message = Some(format!("Error adding document: {}", err)),
+            }
+            Task::none()
+        },
+        Message::RemoveDocument(id) => {
+            let Some(position) = state.documents.iter().position(|doc| doc.id == id) else {
+                return Task::none();
+            };
+
+            // Keep documents that applications still point at, so the record stays accurate
+            let used = state.documents[position].sent_with(&state.jobs).len();
+            if used > 0 {
+                state.error_message = Some(format!(
+                    "\"{}\" was sent with {} application(s) and cannot be removed",
+                    state.documents[position].name, used
+                ));
+                return Task::none();
+            }
+
+            match documents::remove_from_library(&state.documents[position]) {
+                Ok(()) => {
+                    state.documents.remove(position);
+                    state.has_unsaved_changes = true;
+                    state.save();
+                }
+                Err(err) => state.error_message = Some(format!("Error removing document: {}", err)),
+            }
+            Task::none()
+        },
+        Message::OpenDocument(id) => {
+            if let Some(document) = state.document(&id) {
+                if let Err(err) = document.open() {
+                    state.error_message = Some(format!("Error opening {}: {}", document.name, err));
+                }
+            }
+            Task::none()
+        },
+        Message::DocumentPicked(kind, id) => {
+            let editing = state.editing_index.is_some();
+            let form = if editing { &mut state.edit_form } else { &mut state.form };
+            match kind {
+                DocumentKind::Resume => form.resume = id,
+                DocumentKind::CoverLetter => form.cover_letter = id,
+            }
+            if editing {
+                state.has_unsaved_changes = true;
+            }
+            Task::none()
+        },
+        Message::ToggleTemplates => {
+            state.show_templates = !state.show_templates;
+            if !state.show_templates {
+                state.editing_template = None;
+                state.save_settings();
+            }
+            Task::none()
+        },
+        Message::EditTemplate(index) => {
+            if let Some(template) = state.settings.templates.get(index) {
+                state.template_body = text_editor::Content::with_text(&template.body);
+                state.editing_template = Some(index);
+            }
+            Task::none()
+        },
+        Message::NewTemplate => {
+            state.settings.templates.push(Template {
+                name: format!("Template {}", state.settings.templates.len() + 1),
+                kind: TemplateKind::FollowUp,
+                subject: String::new(),
+                body: String::new(),
+            });
+            state.editing_template = Some(state.settings.templates.len() - 1);
+            state.template_body = text_editor::Content::new();
+            Task::none()
+        },
+        Message::AddStarterTemplates => {
+            state.settings.templates.extend(Template::starters());
+            state.save_settings();
+            Task::none()
+        },
+        Message::DeleteTemplate(index) => {
+            if index < state.settings.templates.len() {
+                state.settings.templates.remove(index);
+                state.editing_template = None;
+                // Indices into the library shift, so the preview picks again
+                if let Some(preview) = &mut state.template_preview {
+                    preview.template = None;
+                }
+                state.save_settings();
+            }
+            Task::none()
+        },
+        Message::TemplateNameChanged(name) => {
+            if let Some(template) = state.editing_template.and_then(|i| state.settings.templates.get_mut(i)) {
+                template.name = name;
+            }
+            Task::none()
+        },
+        Message::TemplateKindSelected(kind) => {
+            if let Some(template) = state.editing_template.and_then(|i| state.settings.templates.get_mut(i)) {
+                template.kind = kind;
+            }
+            Task::none()
+        },
+        Message::TemplateSubjectChanged(subject) => {
+            if let Some(template) = state.editing_template.and_then(|i| state.settings.templates.get_mut(i)) {
+                template.subject = subject;
+            }
+            Task::none()
+        },
+        Message::TemplateBodyEdited(action) => {
+            let is_edit = action.is_edit();
+            state.template_body.perform(action);
+            if is_edit {
+                if let Some(template) = state.editing_template.and_then(|i| state.settings.templates.get_mut(i)) {
+                    template.body = state.template_body.text().trim_end().to_string();
+                }
+            }
+            Task::none()
+        },
+        Message::ToggleTemplatePreview(index) => {
+            if state.template_preview.as_ref().is_some_and(|preview| preview.job == index) {
+                state.template_preview = None;
+            } else if let Some(job) = state.jobs.get(index) {
+                state.template_preview = Some(TemplatePreview {
+                    job: index,
+                    template: (!state.settings.templates.is_empty()).then_some(0),
+                    // Address the first known contact at the company by default
+                    contact: (!state.company_contacts(&job.company).is_empty()).then_some(0),
+                    exported: None,
+                });
+            }
+            Task::none()
+        },
+        Message::PreviewTemplatePicked(template) => {
+            if let Some(preview) = &mut state.template_preview {
+                preview.template = Some(template);
+                preview.exported = None;
+            }
+            Task::none()
+        },
+        Message::PreviewContactPicked(contact) => {
+            if let Some(preview) = &mut state.template_preview {
+                preview.contact = Some(contact);
+                preview.exported = None;
+            }
+            Task::none()
+        },
+        Message::CopyRendered => {
+            match state.rendered_preview() {
+                Some(rendered) if rendered.subject.is_empty() => iced::clipboard::write(rendered.body),
+                Some(rendered) => {
+                    iced::clipboard::write(format!("Subject: {}\n\n{}", rendered.subject, rendered.body))
+                }
+                None => Task::none(),
+            }
+        },
+        Message::ExportRendered(as_email) => {
+            let Some((job, template, contact)) = state.preview_parts() else {
+                return Task::none();
+            };
+            let rendered = templates::render(template, job, contact);
+            let result = if as_email {
+                templates::export_eml(&rendered, job, template, contact)
+            } else {
+                templates::export_txt(&rendered, job, template)
+            };
+
+            match result {
+                Ok(path) => {
+                    if let Some(preview) = &mut state.template_preview {
+                        preview.exported = Some(path.display().to_string());
+                    }
+                }
+                Err(err) => state.error_message = Some(format!("Error exporting: {}", err)),
+            }
+            Task::none()
+        },
+        Message::EventKindSelected(kind) => {
+            state.active_form_mut().new_event.kind = kind;
+            Task::none()
+        },
+        Message::EventDateChanged(date) => {
+            state.active_form_mut().new_event.date = date;
+            Task::none()
+        },
+        Message::EventTimeChanged(time) => {
+            state.active_form_mut().new_event.time = time;
+            Task::none()
+        },
+        Message::EventTitleChanged(title) => {
+            state.active_form_mut().new_event.title = title;
+            Task::none()
+        },
+        Message::AddEvent => {
+            let form = state.active_form_mut();
+            let Some(event) = form.new_event.to_event() else {
+                state.error_message = Some("Events need a date as YYYY-MM-DD and an optional time as HH:MM".to_string());
+                return Task::none();
+            };
+            form.events.push(event);
+            form.events.sort_by(|a, b| (&a.date, &a.time).cmp(&(&b.date, &b.time)));
+            form.new_event = NewEvent {
+                kind: form.new_event.kind,
+                ..Default::default()
+            };
+            if state.editing_index.is_some() {
+                state.has_unsaved_changes = true;
+            }
+            state.error_message = None;
+            Task::none()
+        },
+        Message::RemoveEvent(id) => {
+            state.active_form_mut().events.retain(|event| event.id != id);
+            if state.editing_index.is_some() {
+                state.has_unsaved_changes = true;
+            }
+            Task::none()
+        },
+        Message::DeadlineKindSelected(kind) => {
+            state.active_form_mut().new_deadline.kind = kind;
+            Task::none()
+        },
+        Message::DeadlineDateChanged(date) => {
+            state.active_form_mut().new_deadline.date = date;
+            Task::none()
+        },
+        Message::DeadlineTimeChanged(time) => {
+            state.active_form_mut().new_deadline.time = time;
+            Task::none()
+        },
+        Message::DeadlineNoteChanged(note) => {
+            state.active_form_mut().new_deadline.note = note;
+            Task::none()
+        },
+        Message::AddDeadline => {
+            let form = state.active_form_mut();
+            let Some(deadline) = form.new_deadline.to_deadline() else {
+                state.error_message = Some("Deadlines need a date as YYYY-MM-DD and an optional time as HH:MM".to_string());
+                return Task::none();
+            };
+            form.deadlines.push(deadline);
+            form.deadlines.sort_by_key(|deadline| deadline.due());
+            form.new_deadline = NewDeadline {
+                kind: form.new_deadline.kind,
+                ..Default::default()
+            };
+            if state.editing_index.is_some() {
+                state.has_unsaved_changes = true;
+            }
+            Task::none()
+        },
+        Message::RemoveDeadline(id) => {
+            state.active_form_mut().deadlines.retain(|deadline| deadline.id != id);
+            if state.editing_index.is_some() {
+                state.has_unsaved_changes = true;
+            }
+            Task::none()
+        },
+        Message::ExportCalendar => {
+            match icalendar::export(&state.jobs) {
+                Ok(path) => state.calendar_exported = Some(path.display().to_string()),
+                Err(err) => state.error_message = Some(format!("Error exporting calendar: {}", err)),
+            }
+            Task::none()
+        },
+        Message::ToggleLiveCalendar => {
+            state.settings.live_calendar = !state.settings.live_calendar;
+            if state.settings.live_calendar {
+                state.write_live_calendar();
+            }
+            state.save_settings();
+            Task::none()
+        },
+        Message::SetViewMode(mode) => {
+            state.view_mode = mode;
+            // The whole month is shown again rather than just the day picked last time
+            if mode == ViewMode::Calendar {
+                state.day_filter = None;
+            }
+            Task::none()
+        },
+        Message::SetListScope(scope) => {
+            // Leaving the trash applies a retention period typed but not submitted
+            if state.list_scope == ListScope::Trash {
+                apply_trash_days(state);
+            }
+            state.list_scope = scope;
+            Task::none()
+        },
+        Message::ToggleArchived(index) => {
+            // Archiving leaves edit mode, so pending edits would be lost
+            if state.editing_index.is_some() && state.has_unsaved_changes {
+                state.error_message = Some("Save or cancel your changes before archiving".to_string());
+                return Task::none();
+            }
+            if let Some(job) = state.jobs.get_mut(index) {
+                job.archived = !job.archived;
+                state.editing_index = None;
+                state.edit_form = FormState::default();
+                state.save();
+            }
+            Task::none()
+        },
+        Message::ArchiveClosed => {
+            for job in state.jobs.iter_mut().filter(|job| !state.pipeline.is_active(&job.status)) {
+                job.archived = true;
+            }
+            state.save_outside_edit();
+            Task::none()
+        },
+        Message::RestoreJob(index) => {
+            if index < state.trash.len() {
+                let restored = state.trash.remove(index).job;
+                // The stage may have been removed from the pipeline while the application was in the trash
+                state.pipeline.adopt_statuses(std::slice::from_ref(&restored));
+                state.jobs.push(restored);
+                state.save_outside_edit();
+                return scan_duplicates(state);
+            }
+            Task::none()
+        },
+        Message::PurgeJob(index) => {
+            if index < state.trash.len() {
+                // Create backup before deleting for good
+                let _ = storage::backup_data();
+                state.trash.remove(index);
+                state.save_outside_edit();
+            }
+            Task::none()
+        },
+        Message::EmptyTrash => {
+            if !state.trash.is_empty() {
+                let _ = storage::backup_data();
+                state.trash.clear();
+                state.save_outside_edit();
+            }
+            Task::none()
+        },
+        Message::TrashDaysChanged(value) => {
+            // Only accept digits; the new period applies on submit
+            if value.chars().all(|c| c.is_ascii_digit()) {
+                state.trash_days_input = value;
+            }
+            Task::none()
+        },
+        Message::TrashDaysSubmitted => {
+            apply_trash_days(state);
+            Task::none()
+        },
+        Message::PurgeExpiredTrash => {
+            state.purge_expired_trash();
+            Task::none()
+        },
+        Message::ShiftCalendarMonth(offset) => {
+            state.calendar_month = calendar::shift_month(state.calendar_month, offset);
+            Task::none()
+        },
+        Message::CalendarToday => {
+            state.calendar_month = calendar::first_of_month(Local::now().date_naive());
+            Task::none()
+        },
+        Message::OpenDay(day) => {
+            state.day_filter = Some(day);
+            state.view_mode = ViewMode::List;
+            Task::none()
+        },
+        Message::ClearDayFilter => {
+            state.day_filter = None;
+            Task::none()
+        },
+    }
+}
+
+// Compare every pair of applications off the UI thread, since the scan is quadratic
+pub fn scan_duplicates(state: &JobTracker) -> Task<Message> {
+    let jobs = state.jobs.clone();
+    Task::perform(
+        async move {
+            tokio::task::spawn_blocking(move || duplicates::find_duplicates(&jobs))
+                .await
+                .unwrap_or_default()
+        },
+        Message::DuplicatesFound,
+    )
+}
+
+// Take the retention period from its input, clamped to 1..=MAX_TRASH_DAYS; the next purge uses it
+fn apply_trash_days(state: &mut JobTracker) {
+    let days = state
+        .trash_days_input
+        .parse::<u32>()
+        .map(|days| days.clamp(1, MAX_TRASH_DAYS))
+        .unwrap_or(state.settings.trash_days);
+    state.trash_days_input = days.to_string();
+    if days != state.settings.trash_days {
+        state.settings.trash_days = days;
+        state.save_settings();
+    }
+}
+
+// Apply the stage name being typed, reporting a rejected name. Returns false if it was rejected
+fn finish_stage_rename(state: &mut JobTracker) -> bool {
+    match state.commit_stage_rename() {
+        Ok(renamed) => {
+            if renamed {
+                state.has_unsaved_changes = true;
+                // Saved views filtering on the stage follow the new name
+                state.save_settings();
+            }
+            true
+        }
+        Err(err) => {
+            state.error_message = Some(err);
+            false
+        }
+    }
+}
+
+// Closure reasons only apply to rejected or withdrawn applications, and default
+// the stage reached to where the application was before closing
+fn finish_closure_reason(job: &mut JobApplication, pipeline: &Pipeline, default_stage: Option<String>) {
+    if !pipeline.outcome(&job.status).is_negative() {
+        job.closure_reason = None;
+    } else if let Some(reason) = job.closure_reason.as_mut().filter(|r| r.stage_reached.is_empty()) {
+        reason.stage_reached = default_stage.unwrap_or_default();
+    }
+}
+
+// Keep track of held modifier keys so shift-click can add secondary sort keys
+pub fn subscription(state: &JobTracker) -> Subscription<Message> {
+    let modifiers = event::listen_with(|event, _status, _window| match event {
+        Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+            Some(Message::ModifiersChanged(modifiers))
+        }
+        _ => None,
+    });
+
+    // Arrow and page keys move between months, unless a text input has focus
+    let calendar_keys = (state.view_mode == ViewMode::Calendar).then(|| {
+        keyboard::on_key_press(|key, _modifiers| match key {
+            keyboard::Key::Named(keyboard::key::Named::ArrowLeft | keyboard::key::Named::PageUp) => {
+                Some(Message::ShiftCalendarMonth(-1))
+            }
+            keyboard::Key::Named(keyboard::key::Named::ArrowRight | keyboard::key::Named::PageDown) => {
+                Some(Message::ShiftCalendarMonth(1))
+            }
+            keyboard::Key::Named(keyboard::key::Named::Home) => Some(Message::CalendarToday),
+            _ => None,
+        })
+    });
+
+    // Changes to the data file made by other programs or instances
+    let file_check = iced::time::every(Duration::from_secs(sync::POLL_SECONDS)).map(|_| Message::CheckDataFile);
+
+    // Trash expires by the day, so an hourly check is plenty for sessions left open
+    let trash_purge = iced::time::every(Duration::from_secs(60 * 60)).map(|_| Message::PurgeExpiredTrash);
+
+    Subscription::batch([modifiers, file_check, trash_purge].into_iter().chain(calendar_keys))
 }